@@ -29,7 +29,7 @@ extension AppState {
         }
 
         // Check rules
-        for port in ports {
+        for port in ports where !port.isSystemProtected {
             let key = "\(port.port)-\(port.pid)"
             guard let firstSeen = Self.portFirstSeen[key] else { continue }
 
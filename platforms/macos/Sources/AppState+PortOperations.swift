@@ -1,4 +1,4 @@
-import Foundation
+import AppKit
 
 extension AppState {
     /// Refreshes the port list by scanning for active ports.
@@ -15,7 +15,12 @@ extension AppState {
             hasPendingRefreshRequest = false
             isScanning = true
 
-            let scanned = await scanner.scanPorts()
+            guard let scanned = await scanner.scanPorts() else {
+                // Keep the previous list: an unknown result must not look like
+                // every port went away (and fire stop notifications)
+                isScanning = false
+                continue
+            }
             let previousPorts = ports
             let didChange = updatePorts(scanned)
             didChangeAny = didChangeAny || didChange
@@ -55,16 +60,34 @@ extension AppState {
 
     /// Kills the process using the specified port.
     func killPort(_ port: PortInfo) async {
-        if await scanner.killProcessGracefully(pid: port.pid) {
-            ports.removeAll { $0.id == port.id }
-            await refresh()
+        guard !port.isSystemProtected else {
+            presentKillError(.systemProtected(pid: port.pid, processName: port.processName))
+            return
+        }
+
+        let result = await scanner.killProcessGracefully(pid: port.pid)
+        guard result.processGone else {
+            presentKillError(killError(for: port, result: result))
+            return
         }
+
+        ports.removeAll { $0.id == port.id }
+        await refresh()
     }
 
     /// Kills the listening process and all processes with ESTABLISHED connections to the port.
     func killPortDeep(_ port: PortInfo) async {
+        guard !port.isSystemProtected else {
+            presentKillError(.systemProtected(pid: port.pid, processName: port.processName))
+            return
+        }
+
         // 1. Kill the listener
-        _ = await scanner.killProcessGracefully(pid: port.pid)
+        let result = await scanner.killProcessGracefully(pid: port.pid)
+        guard result.processGone else {
+            presentKillError(killError(for: port, result: result))
+            return
+        }
 
         // 2. Find and kill ESTABLISHED connections
         let establishedPids = await scanner.findEstablishedPids(for: port.port)
@@ -76,12 +99,51 @@ extension AppState {
         await refresh()
     }
 
+    /// Kills every process in a group once, regardless of how many ports it holds.
+    /// Protected Apple system daemons are skipped silently.
+    func killProcessGroup(_ group: ProcessGroup) async {
+        let pids = Set(group.ports.filter { !$0.isSystemProtected }.map(\.pid))
+        for pid in pids {
+            _ = await scanner.killProcessGracefully(pid: pid)
+        }
+        ports.removeAll { pids.contains($0.pid) }
+        await refresh()
+    }
+
     /// Kills all processes currently using ports.
+    /// Protected Apple system daemons are skipped silently.
     func killAll() async {
-        for port in ports {
+        for port in ports where !port.isSystemProtected {
             _ = await scanner.killProcessGracefully(pid: port.pid)
         }
         ports.removeAll()
         await refresh()
     }
+
+    /// Explains why signalling a port's process failed.
+    ///
+    /// EPERM is classified at this point: an Apple system daemon owned by
+    /// another user gets the protected-process explanation, anything else
+    /// the errno description.
+    private func killError(for port: PortInfo, result: KillResult) -> PortKillerError {
+        guard case .failed(let code) = result else {
+            return .killFailed(pid: port.pid, reason: "Unknown error")
+        }
+        if code == EPERM && SystemProcessGuard.isProtected(pid: port.pid) {
+            return .systemProtected(pid: port.pid, processName: port.processName)
+        }
+        return .killFailed(pid: port.pid, reason: String(cString: strerror(code)))
+    }
+
+    /// Shows a kill error with its failure reason and recovery suggestion.
+    func presentKillError(_ error: PortKillerError) {
+        let alert = NSAlert()
+        alert.alertStyle = .warning
+        alert.messageText = error.errorDescription ?? "Failed to kill process"
+        alert.informativeText = [error.failureReason, error.recoverySuggestion]
+            .compactMap { $0 }
+            .joined(separator: "\n\n")
+        alert.addButton(withTitle: "OK")
+        alert.runModal()
+    }
 }
@@ -25,4 +25,31 @@ extension AppState {
     func checkWatchedPorts() {
         watchedPortsState.checkForChanges(ports: ports)
     }
+
+    /// Routes watched-port notification actions back into the app
+    func setupNotificationActions() {
+        NotificationService.shared.actionHandler = { [weak self] action, port, pids in
+            self?.handleNotificationAction(action, port: port, pids: pids)
+        }
+    }
+
+    /// Handles "Kill it" / "Ignore for 1h" pressed on a watched-port notification
+    ///
+    /// "Kill it" only targets the PIDs the notification named. If the port has
+    /// since moved to another process (e.g. a crash-looping server restarted),
+    /// nothing is killed.
+    func handleNotificationAction(_ action: WatchedPortAction, port: Int, pids: [Int]) {
+        switch action {
+        case .kill:
+            let targets = ports.filter { $0.port == port && $0.isActive && pids.contains($0.pid) }
+            guard !targets.isEmpty else { return }
+            Task {
+                for target in targets {
+                    await killPort(target)
+                }
+            }
+        case .ignore:
+            watchedPortsState.mute(port, for: AppConstants.notificationMuteDuration)
+        }
+    }
 }
@@ -11,6 +11,7 @@ extension Defaults.Keys {
     static let watchedPorts = Key<[WatchedPort]>("watchedPorts", default: [])
     static let useTreeView = Key<Bool>("useTreeView", default: false)
     static let hideSystemProcesses = Key<Bool>("hideSystemProcesses", default: false)
+    static let showProtectedProcesses = Key<Bool>("showProtectedProcesses", default: false)
     static let skipKillConfirmation = Key<Bool>("skipKillConfirmation", default: false)
     static let refreshInterval = Key<Int>("refreshInterval", default: 5)
     static let cloudflaredProtocol = Key<CloudflaredProtocol>("cloudflaredProtocol", default: .http2)
@@ -108,6 +109,7 @@ final class AppState {
         let filterActive: Bool
         let filterText: String
         let hideSystem: Bool
+        let showProtected: Bool
         let favoritesCount: Int
         let watchedCount: Int
     }
@@ -122,6 +124,7 @@ final class AppState {
             filterActive: filter.isActive,
             filterText: filter.searchText,
             hideSystem: Defaults[.hideSystemProcesses],
+            showProtected: Defaults[.showProtectedProcesses],
             favoritesCount: favorites.count,
             watchedCount: watchedPorts.count
         )
@@ -179,6 +182,10 @@ final class AppState {
             result = result.filter { $0.processType != .system }
         }
 
+        if !Defaults[.showProtectedProcesses] {
+            result = result.filter { !$0.isSystemProtected }
+        }
+
         return result
     }
 
@@ -235,6 +242,7 @@ final class AppState {
         self.watchedPortsState = watchedPortsState ?? WatchedPortsState()
 
         setupKeyboardShortcuts()
+        setupNotificationActions()
         startAutoRefresh()
     }
 
@@ -49,6 +49,9 @@ enum AppConstants {
     /// Grace period between SIGTERM and SIGKILL when killing processes
     static let killGracePeriod: Duration = .milliseconds(500)
 
+    /// How long "Ignore for 1h" silences a watched port's notifications
+    static let notificationMuteDuration: TimeInterval = 3600
+
     /// Maximum length for displayed command strings
     static let maxCommandLength: Int = 200
 
@@ -26,6 +26,9 @@ enum PortKillerError: Error, LocalizedError {
     /// Network or system operation error
     case networkError(String)
 
+    /// Process is an Apple system daemon owned by another user that can't be signalled
+    case systemProtected(pid: Int, processName: String)
+
     /// User-friendly error description
     var errorDescription: String? {
         switch self {
@@ -37,6 +40,8 @@ enum PortKillerError: Error, LocalizedError {
             return "Permission denied. PortKiller requires accessibility permissions to manage processes."
         case .networkError(let reason):
             return "Network error: \(reason)"
+        case .systemProtected(let pid, let processName):
+            return "\(processName) (PID \(pid)) is a protected macOS system process."
         }
     }
 
@@ -51,6 +56,8 @@ enum PortKillerError: Error, LocalizedError {
             return "PortKiller does not have the necessary system permissions."
         case .networkError:
             return "A network or system-level error occurred."
+        case .systemProtected:
+            return "It is an Apple-signed system daemon running as another user, so macOS rejects signals from PortKiller."
         }
     }
 
@@ -65,6 +72,8 @@ enum PortKillerError: Error, LocalizedError {
             return "Go to System Settings > Privacy & Security > Accessibility and enable PortKiller."
         case .networkError:
             return "Check your network connection and try again."
+        case .systemProtected:
+            return "launchd restarts these daemons on demand. Disable the feature that uses the port in System Settings instead."
         }
     }
 
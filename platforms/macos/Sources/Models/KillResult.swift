@@ -0,0 +1,30 @@
+/**
+ * KillResult.swift
+ * PortKiller
+ *
+ * Outcome of signalling a process.
+ * Keeps the kill(2) errno so callers can explain a failure instead of
+ * silently leaving the process on screen.
+ */
+
+import Foundation
+import Darwin
+
+/// Result of sending a signal to a process
+enum KillResult: Sendable, Equatable {
+    /// The signal was delivered
+    case killed
+
+    /// kill(2) failed; carries its errno (EPERM, ESRCH, ...)
+    case failed(errno: Int32)
+
+    /// True if the process is gone: killed, or already exited (ESRCH)
+    var processGone: Bool {
+        switch self {
+        case .killed, .failed(errno: ESRCH):
+            return true
+        case .failed:
+            return false
+        }
+    }
+}
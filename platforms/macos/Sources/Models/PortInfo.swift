@@ -46,6 +46,12 @@ struct PortInfo: Identifiable, Hashable, Sendable {
     /// Detected process type (cached at construction time for performance)
     let processType: ProcessType
 
+    /// Whether the process is an Apple system daemon PortKiller can't signal
+    let isSystemProtected: Bool
+
+    /// CPU architecture of the process on Apple Silicon (nil on Intel or if unknown)
+    let architecture: ProcessArchitecture?
+
     /// Formatted port number for display (e.g., ":3000")
     var displayPort: String { ":\(port)" }
 
@@ -63,7 +69,9 @@ struct PortInfo: Identifiable, Hashable, Sendable {
             command: "",
             fd: "",
             isActive: false,
-            processType: .other
+            processType: .other,
+            isSystemProtected: false,
+            architecture: nil
         )
     }
 
@@ -77,8 +85,20 @@ struct PortInfo: Identifiable, Hashable, Sendable {
     ///   - user: Username of the process owner
     ///   - command: Full command line
     ///   - fd: File descriptor information
+    ///   - isSystemProtected: Whether the process is an unkillable Apple system daemon
+    ///   - architecture: CPU architecture of the process, if known
     /// - Returns: An active PortInfo instance
-    static func active(port: Int, pid: Int, processName: String, address: String, user: String, command: String, fd: String) -> PortInfo {
+    static func active(
+        port: Int,
+        pid: Int,
+        processName: String,
+        address: String,
+        user: String,
+        command: String,
+        fd: String,
+        isSystemProtected: Bool = false,
+        architecture: ProcessArchitecture? = nil
+    ) -> PortInfo {
         // Check for user-defined process type override first
         let processType: ProcessType
         if let overrideRaw = Defaults[.processTypeOverrides][processName],
@@ -97,7 +117,9 @@ struct PortInfo: Identifiable, Hashable, Sendable {
             command: command,
             fd: fd,
             isActive: true,
-            processType: processType
+            processType: processType,
+            isSystemProtected: isSystemProtected,
+            architecture: architecture
         )
     }
 }
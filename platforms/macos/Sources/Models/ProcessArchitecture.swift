@@ -0,0 +1,35 @@
+/**
+ * ProcessArchitecture.swift
+ * PortKiller
+ *
+ * CPU architecture a process is running as on Apple Silicon.
+ * Used to flag x86_64 binaries running under Rosetta translation,
+ * which are a common cause of unexpectedly slow local services.
+ */
+
+import Foundation
+import Darwin
+
+/// Architecture of a running process
+enum ProcessArchitecture: String, Sendable {
+    /// Native Apple Silicon process
+    case arm64 = "arm64"
+
+    /// Intel binary translated by Rosetta 2
+    case rosetta = "x86_64 (Rosetta)"
+
+    /// Detect the architecture of a process from its kernel flags
+    ///
+    /// Checks `P_TRANSLATED` in the `p_flag` field of the process's `kinfo_proc`.
+    /// Only meaningful on Apple Silicon; returns nil on Intel Macs.
+    ///
+    /// - Parameter processFlags: `kp_proc.p_flag` from `sysctl(KERN_PROC_PID)`
+    /// - Returns: The process architecture, or nil if unknown
+    nonisolated static func detect(processFlags: Int32) -> ProcessArchitecture? {
+        #if arch(arm64)
+        return processFlags & P_TRANSLATED != 0 ? .rosetta : .arm64
+        #else
+        return nil
+        #endif
+    }
+}
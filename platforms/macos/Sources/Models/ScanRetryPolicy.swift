@@ -0,0 +1,40 @@
+/**
+ * ScanRetryPolicy.swift
+ * PortKiller
+ *
+ * Retry policy for the lsof invocation behind each port scan.
+ * lsof occasionally fails transiently under load; retrying with a short,
+ * jittered backoff avoids treating one bad run as "every port went away".
+ */
+
+import Foundation
+
+/// How often a failed lsof run is retried and how long to wait in between
+struct ScanRetryPolicy: Sendable, Equatable {
+    /// Total lsof attempts per scan (1 disables retrying)
+    var maxAttempts: Int
+
+    /// Backoff after the first failed attempt; doubled for each further attempt
+    var baseDelay: Duration
+
+    /// Fraction of each delay randomly added or removed (0.5 = ±50%)
+    var jitter: Double
+
+    /// Three attempts, starting at 100ms with ±50% jitter
+    static let `default` = ScanRetryPolicy(maxAttempts: 3, baseDelay: .milliseconds(100), jitter: 0.5)
+
+    /// Never retry
+    static let none = ScanRetryPolicy(maxAttempts: 1, baseDelay: .zero, jitter: 0)
+
+    /// Delay before the next attempt: baseDelay × 2^(attempt-1), jittered.
+    ///
+    /// - Parameters:
+    ///   - attempt: 1-based number of the attempt that just failed
+    ///   - random: Jitter sample in -1...1 (injectable for deterministic tests)
+    /// - Returns: How long to sleep before retrying
+    nonisolated func delay(afterAttempt attempt: Int, random: Double = .random(in: -1...1)) -> Duration {
+        let exponential = Double(1 << max(0, attempt - 1))
+        let factor = 1 + jitter * min(max(random, -1), 1)
+        return baseDelay * (exponential * factor)
+    }
+}
@@ -18,33 +18,131 @@ import Darwin
  */
 actor PortScanner: PortScannerProtocol {
 
+    /// Retry policy for transient lsof failures
+    private let retryPolicy: ScanRetryPolicy
+
+    init(retryPolicy: ScanRetryPolicy = .default) {
+        self.retryPolicy = retryPolicy
+    }
+
     /**
      * Scans all listening TCP ports using lsof.
      *
-     * Executes: `lsof -iTCP -sTCP:LISTEN -P -n +c 0`
+     * Executes: `lsof -iTCP -sTCP:LISTEN -P -n -w +c 0`
      *
      * Flags explained:
      * - -iTCP: Show only TCP connections
      * - -sTCP:LISTEN: Show only listening sockets
      * - -P: Show port numbers (don't resolve to service names)
      * - -n: Show IP addresses (don't resolve to hostnames)
+     * - -w: Suppress warnings, so anything on stderr is a real error
      * - +c 0: Show full command name (unlimited length)
      *
-     * @returns Array of PortInfo objects representing all listening ports
+     * @returns Array of PortInfo objects representing all listening ports,
+     *          or nil if lsof kept failing and the scan result is unknown
+     */
+    func scanPorts() async -> [PortInfo]? {
+        guard let output = await runLsofWithRetry() else { return nil }
+
+        guard !output.isEmpty else { return [] }
+
+        // Extract PIDs from lsof output, then get command lines via sysctl (no process spawn)
+        let pids = extractPids(from: output)
+        let commands = pids.isEmpty ? [:] : getProcessCommands(for: pids)
+
+        // One sysctl(KERN_PROC_PID) per PID feeds both the protection check and Rosetta detection
+        var protectedPids = Set<Int>()
+        var architectures: [Int: ProcessArchitecture] = [:]
+        for pid in pids {
+            let info = Self.kernelProcessInfo(for: pid)
+            if SystemProcessGuard.isProtected(pid: pid, ownerUid: info?.kp_eproc.e_ucred.cr_uid) {
+                protectedPids.insert(pid)
+            }
+            if let info {
+                architectures[pid] = ProcessArchitecture.detect(processFlags: info.kp_proc.p_flag)
+            }
+        }
+
+        return parseLsofOutput(
+            output,
+            commands: commands,
+            protectedPids: protectedPids,
+            architectures: architectures
+        )
+    }
+
+    /// Outcome of a single lsof invocation
+    enum LsofResult: Equatable, Sendable {
+        /// lsof completed; empty output means no listening sockets
+        case output(String)
+        /// lsof reported an error or was killed (e.g. resource pressure under load)
+        case transientFailure
+        /// lsof couldn't be launched at all; retrying won't help
+        case fatalFailure
+    }
+
+    /**
+     * Runs lsof, retrying transient failures with jittered exponential backoff.
+     *
+     * @returns Raw lsof output ("" when nothing is listening),
+     *          or nil if lsof failed on every attempt
+     */
+    private func runLsofWithRetry() async -> String? {
+        let maxAttempts = max(1, retryPolicy.maxAttempts)
+        for attempt in 1...maxAttempts {
+            switch runLsof() {
+            case .output(let output):
+                return output
+            case .fatalFailure:
+                return nil
+            case .transientFailure where attempt < maxAttempts:
+                try? await Task.sleep(for: retryPolicy.delay(afterAttempt: attempt))
+            case .transientFailure:
+                print("[PortScanner] lsof failed after \(maxAttempts) attempts")
+            }
+        }
+        return nil
+    }
+
+    /**
+     * Classifies a finished lsof run.
+     *
+     * lsof exits 1 both when nothing matches and when it hits an error. With
+     * warnings suppressed (-w), "no listeners" is silent while a real failure
+     * explains itself on stderr. Any stdout is kept even on a non-zero exit,
+     * since a partial listing beats none.
+     *
+     * @param status - lsof exit status
+     * @param output - Captured stdout
+     * @param errors - Captured stderr
+     * @returns How the scan should proceed
      */
-    func scanPorts() async -> [PortInfo] {
+    nonisolated static func classifyLsofRun(status: Int32, output: String, errors: String) -> LsofResult {
+        if status == 0 || !output.isEmpty {
+            return .output(output)
+        }
+        let hasErrors = !errors.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+        if status == 1 && !hasErrors {
+            return .output("")
+        }
+        return .transientFailure
+    }
+
+    /// Runs `lsof -iTCP -sTCP:LISTEN -P -n -w +c 0` once.
+    nonisolated private func runLsof() -> LsofResult {
         // Wrap entire Process/Pipe lifecycle in autoreleasepool to release Obj-C bridged
         // objects (Process, Pipe, FileHandle, URL, Data) immediately after each scan.
         // Without this, these objects accumulate across the long-lived scanning Task,
         // causing ~35KB per scan × 47,520 scans over 66 hours = ~1.7GB leak.
-        let output: String = autoreleasepool {
+        autoreleasepool {
             let process = Process()
             process.executableURL = URL(fileURLWithPath: "/usr/sbin/lsof")
-            process.arguments = ["-iTCP", "-sTCP:LISTEN", "-P", "-n", "+c", "0"]
+            process.arguments = ["-iTCP", "-sTCP:LISTEN", "-P", "-n", "-w", "+c", "0"]
 
             let pipe = Pipe()
+            let errorPipe = Pipe()
             process.standardOutput = pipe
-            process.standardError = FileHandle.nullDevice
+            process.standardError = errorPipe
 
             do {
                 try process.run()
@@ -52,22 +150,31 @@ actor PortScanner: PortScannerProtocol {
                 // CRITICAL: Read data BEFORE waitUntilExit to avoid deadlock.
                 // If lsof output exceeds the pipe buffer (~64KB), lsof blocks waiting
                 // to write. If we waitUntilExit first, we deadlock.
+                // stderr is read second: with -w it only carries short error lines.
                 let data = pipe.fileHandleForReading.readDataToEndOfFile()
+                let errorData = errorPipe.fileHandleForReading.readDataToEndOfFile()
                 process.waitUntilExit()
 
-                return String(data: data, encoding: .utf8) ?? ""
+                guard process.terminationReason == .exit else {
+                    return .transientFailure
+                }
+
+                let output = String(data: data, encoding: .utf8) ?? ""
+                let errors = String(data: errorData, encoding: .utf8) ?? ""
+                let result = Self.classifyLsofRun(
+                    status: process.terminationStatus,
+                    output: output,
+                    errors: errors
+                )
+                if result == .transientFailure {
+                    print("[PortScanner] lsof exited \(process.terminationStatus): \(errors)")
+                }
+                return result
             } catch {
                 print("[PortScanner] Failed to scan ports: \(error.localizedDescription)")
-                return ""
+                return .fatalFailure
             }
         }
-
-        guard !output.isEmpty else { return [] }
-
-        // Extract PIDs from lsof output, then get command lines via sysctl (no process spawn)
-        let pids = extractPids(from: output)
-        let commands = pids.isEmpty ? [:] : getProcessCommands(for: pids)
-        return parseLsofOutput(output, commands: commands)
     }
 
     /// Extracts unique PIDs from raw lsof output (second column of each data line).
@@ -110,6 +217,16 @@ actor PortScanner: PortScannerProtocol {
         return commands
     }
 
+    /// Reads a process's kernel info (flags, credentials) via sysctl(KERN_PROC_PID).
+    nonisolated static func kernelProcessInfo(for pid: Int) -> kinfo_proc? {
+        var info = kinfo_proc()
+        var size = MemoryLayout<kinfo_proc>.stride
+        var mib: [Int32] = [CTL_KERN, KERN_PROC, KERN_PROC_PID, Int32(pid)]
+
+        guard sysctl(&mib, 4, &info, &size, nil, 0) == 0, size > 0 else { return nil }
+        return info
+    }
+
     /// Reads a process's full command line (argv) from the kernel via sysctl.
     ///
     /// KERN_PROCARGS2 returns: [argc: Int32][exec_path\0][\0 padding][argv[0]\0][argv[1]\0]...
@@ -176,9 +293,16 @@ actor PortScanner: PortScannerProtocol {
      *
      * @param output - Raw string output from lsof command
      * @param commands - Dictionary of PID to full command string from ps
+     * @param protectedPids - PIDs of unkillable Apple system daemons
+     * @param architectures - Dictionary of PID to CPU architecture
      * @returns Array of unique PortInfo objects, sorted by port number
      */
-    nonisolated private func parseLsofOutput(_ output: String, commands: [Int: String]) -> [PortInfo] {
+    nonisolated private func parseLsofOutput(
+        _ output: String,
+        commands: [Int: String],
+        protectedPids: Set<Int>,
+        architectures: [Int: ProcessArchitecture]
+    ) -> [PortInfo] {
         var ports: [PortInfo] = []
         var seen: Set<String> = []
         // Use split for zero-copy Substring iteration (no allocation per line)
@@ -226,7 +350,16 @@ actor PortScanner: PortScannerProtocol {
             // Get full command from ps output
             let command = commands[pid] ?? processName
 
-            guard let portInfo = parseAddress(String(addressPart), processName: processName, pid: pid, user: user, command: command, fd: fd) else {
+            guard let portInfo = parseAddress(
+                String(addressPart),
+                processName: processName,
+                pid: pid,
+                user: user,
+                command: command,
+                fd: fd,
+                isSystemProtected: protectedPids.contains(pid),
+                architecture: architectures[pid]
+            ) else {
                 continue
             }
 
@@ -253,9 +386,20 @@ actor PortScanner: PortScannerProtocol {
      * @param user - User running the process
      * @param command - Full command line of the process
      * @param fd - File descriptor number
+     * @param isSystemProtected - Whether the process is an unkillable Apple system daemon
+     * @param architecture - CPU architecture of the process, if known
      * @returns PortInfo object or nil if parsing fails
      */
-    nonisolated private func parseAddress(_ address: String, processName: String, pid: Int, user: String, command: String, fd: String) -> PortInfo? {
+    nonisolated private func parseAddress(
+        _ address: String,
+        processName: String,
+        pid: Int,
+        user: String,
+        command: String,
+        fd: String,
+        isSystemProtected: Bool,
+        architecture: ProcessArchitecture?
+    ) -> PortInfo? {
         let parts: [String]
 
         if address.hasPrefix("[") {
@@ -287,7 +431,9 @@ actor PortScanner: PortScannerProtocol {
             address: addr.isEmpty ? "*" : addr,
             user: user,
             command: command,
-            fd: fd
+            fd: fd,
+            isSystemProtected: isSystemProtected,
+            architecture: architecture
         )
     }
 
@@ -342,11 +488,11 @@ actor PortScanner: PortScannerProtocol {
      *
      * @param pid - The process ID to kill
      * @param force - If true, sends SIGKILL (-9) instead of SIGTERM (-15)
-     * @returns True if the kill command executed successfully (exit code 0)
+     * @returns .killed if the signal was delivered, otherwise the kill(2) errno
      */
-    func killProcess(pid: Int, force: Bool = false) async -> Bool {
+    func killProcess(pid: Int, force: Bool = false) async -> KillResult {
         // Direct syscall — no Process/Pipe/FileHandle overhead
-        Darwin.kill(Int32(pid), force ? SIGKILL : SIGTERM) == 0
+        Darwin.kill(Int32(pid), force ? SIGKILL : SIGTERM) == 0 ? .killed : .failed(errno: errno)
     }
 
     /**
@@ -363,19 +509,30 @@ actor PortScanner: PortScannerProtocol {
      * - Send shutdown notifications
      * - Clean up temporary resources
      *
+     * If SIGTERM is rejected (EPERM, ESRCH) SIGKILL would be too, so that
+     * failure is returned as is. A SIGKILL hitting ESRCH means the process
+     * already exited after SIGTERM, which counts as killed.
+     *
      * @param pid - The process ID to kill
-     * @returns True if either kill command succeeded
+     * @returns .killed on success, otherwise the errno of the failed signal
      */
-    func killProcessGracefully(pid: Int) async -> Bool {
+    func killProcessGracefully(pid: Int) async -> KillResult {
         // Try SIGTERM first (allows graceful shutdown)
         let graceful = await killProcess(pid: pid, force: false)
-        if graceful {
-            // Give the process time to clean up (500ms grace period)
-            try? await Task.sleep(for: .milliseconds(500))
+        if case .failed = graceful {
+            return graceful
         }
 
+        // Give the process time to clean up (500ms grace period)
+        try? await Task.sleep(for: .milliseconds(500))
+
         // Force kill with SIGKILL (immediate termination)
-        return await killProcess(pid: pid, force: true)
+        switch await killProcess(pid: pid, force: true) {
+        case .killed, .failed(errno: ESRCH):
+            return .killed
+        case .failed(let code):
+            return .failed(errno: code)
+        }
     }
 
     /**
@@ -20,6 +20,15 @@ protocol NotificationServiceProtocol {
     ///   - body: Notification body text
     func notify(title: String, body: String)
 
+    /// Sends a watched-port notification with action buttons
+    /// - Parameters:
+    ///   - port: Watched port number the notification is about
+    ///   - pids: PIDs owning the port when the notification is sent
+    ///   - title: Notification title
+    ///   - body: Notification body text
+    ///   - canKill: Whether to offer the "Kill it" action
+    func notifyWatchedPort(_ port: Int, pids: [Int], title: String, body: String, canKill: Bool)
+
     /// Requests notification permission from the user
     /// - Returns: True if permission was granted
     func requestPermission() async -> Bool
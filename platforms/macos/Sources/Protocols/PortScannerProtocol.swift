@@ -11,20 +11,21 @@ import Foundation
 /// Protocol for port scanning and process management operations
 protocol PortScannerProtocol: Sendable {
     /// Scans for all listening TCP ports on the system
-    /// - Returns: Array of PortInfo representing active ports
-    func scanPorts() async -> [PortInfo]
+    /// - Returns: Array of PortInfo representing active ports,
+    ///   or nil if the scan failed and the result is unknown
+    func scanPorts() async -> [PortInfo]?
 
     /// Kills a process by PID
     /// - Parameters:
     ///   - pid: Process ID to kill
     ///   - force: If true, uses SIGKILL; otherwise uses SIGTERM
-    /// - Returns: True if the process was successfully killed
-    func killProcess(pid: Int, force: Bool) async -> Bool
+    /// - Returns: Whether the signal was delivered, or the errno it failed with
+    func killProcess(pid: Int, force: Bool) async -> KillResult
 
     /// Kills a process gracefully with fallback to force kill
     /// - Parameter pid: Process ID to kill
-    /// - Returns: True if the process was successfully killed
-    func killProcessGracefully(pid: Int) async -> Bool
+    /// - Returns: Whether the process was killed, or the errno it failed with
+    func killProcessGracefully(pid: Int) async -> KillResult
 
     /// Finds PIDs of processes with ESTABLISHED connections to a port
     /// - Parameter port: Port number to check
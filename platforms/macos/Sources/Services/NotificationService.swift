@@ -3,13 +3,23 @@
  * PortKiller
  *
  * Manages system notifications for watched port events.
- * Handles notification center setup, permissions, and message delivery.
+ * Handles notification center setup, permissions, message delivery,
+ * and the "Kill it" / "Ignore for 1h" actions on watched-port alerts.
  */
 
 import Foundation
 import SwiftUI
 @preconcurrency import UserNotifications
 
+/// Action buttons offered on watched-port notifications
+enum WatchedPortAction: String, Sendable {
+    /// Kill the process currently listening on the port
+    case kill = "KILL_PORT"
+
+    /// Silence notifications for the port for a while
+    case ignore = "IGNORE_PORT"
+}
+
 /// Service for managing system notifications
 ///
 /// NotificationService centralizes all notification-related functionality,
@@ -20,6 +30,22 @@ final class NotificationService: NSObject, NotificationServiceProtocol {
     /// Singleton instance
     static let shared = NotificationService()
 
+    /// Category for alerts about a watched port that is in use (kill + ignore)
+    private static let activePortCategory = "WATCHED_PORT_ACTIVE"
+
+    /// Category for alerts about a watched port that became free (ignore only)
+    private static let freePortCategory = "WATCHED_PORT_FREE"
+
+    /// userInfo key carrying the port number of a watched-port alert
+    nonisolated private static let portKey = "port"
+
+    /// userInfo key carrying the PIDs the alert was about
+    nonisolated private static let pidsKey = "pids"
+
+    /// Called when the user presses an action button on a watched-port alert,
+    /// with the port and the PIDs that owned it when the alert was sent
+    var actionHandler: ((WatchedPortAction, Int, [Int]) -> Void)?
+
     /// Notification center instance (nil if not running as .app bundle)
     private var notificationCenter: UNUserNotificationCenter? {
         // UNUserNotificationCenter only works in .app bundle
@@ -39,6 +65,7 @@ final class NotificationService: NSObject, NotificationServiceProtocol {
     func setup() {
         guard let center = notificationCenter else { return }
         center.delegate = self
+        registerCategories(on: center)
 
         Task {
             let settings = await center.notificationSettings()
@@ -105,6 +132,71 @@ final class NotificationService: NSObject, NotificationServiceProtocol {
 
         center.add(request)
     }
+
+    /// Send a notification about a watched port, with action buttons
+    ///
+    /// Alerts for an in-use port offer "Kill it" and "Ignore for 1h";
+    /// alerts for a freed port only offer "Ignore for 1h".
+    ///
+    /// - Parameters:
+    ///   - port: Watched port number the alert is about
+    ///   - pids: PIDs owning the port when the alert is sent ("Kill it" only targets these)
+    ///   - title: Notification title
+    ///   - body: Notification body message
+    ///   - canKill: Whether to offer the "Kill it" action
+    func notifyWatchedPort(_ port: Int, pids: [Int], title: String, body: String, canKill: Bool) {
+        guard let center = notificationCenter else { return }
+
+        let content = UNMutableNotificationContent()
+        content.title = title
+        content.body = body
+        content.sound = .default
+        content.categoryIdentifier = canKill ? Self.activePortCategory : Self.freePortCategory
+        content.userInfo = [Self.portKey: port, Self.pidsKey: pids]
+
+        let request = UNNotificationRequest(
+            identifier: UUID().uuidString,
+            content: content,
+            trigger: nil
+        )
+
+        center.add(request)
+    }
+
+    /// Registers the action categories used by watched-port alerts
+    private func registerCategories(on center: UNUserNotificationCenter) {
+        let kill = UNNotificationAction(
+            identifier: WatchedPortAction.kill.rawValue,
+            title: "Kill it",
+            options: [.destructive]
+        )
+        let ignore = UNNotificationAction(
+            identifier: WatchedPortAction.ignore.rawValue,
+            title: "Ignore for 1h",
+            options: []
+        )
+
+        center.setNotificationCategories([
+            UNNotificationCategory(
+                identifier: Self.activePortCategory,
+                actions: [kill, ignore],
+                intentIdentifiers: [],
+                options: []
+            ),
+            UNNotificationCategory(
+                identifier: Self.freePortCategory,
+                actions: [ignore],
+                intentIdentifiers: [],
+                options: []
+            )
+        ])
+    }
+
+    /// Routes a notification action back to the app
+    private func handleAction(_ identifier: String, port: Int, pids: [Int]) {
+        guard let action = WatchedPortAction(rawValue: identifier) else { return }
+        actionHandler?(action, port, pids)
+    }
 }
 
 // MARK: - UNUserNotificationCenterDelegate
@@ -118,4 +210,22 @@ extension NotificationService: UNUserNotificationCenterDelegate {
     ) {
         completionHandler([.banner, .sound])
     }
+
+    /// Handle action buttons pressed on delivered notifications
+    nonisolated func userNotificationCenter(
+        _ center: UNUserNotificationCenter,
+        didReceive response: UNNotificationResponse,
+        withCompletionHandler completionHandler: @escaping () -> Void
+    ) {
+        let identifier = response.actionIdentifier
+        let userInfo = response.notification.request.content.userInfo
+        let port = userInfo[Self.portKey] as? Int
+        let pids = userInfo[Self.pidsKey] as? [Int] ?? []
+        completionHandler()
+
+        guard let port else { return }
+        Task { @MainActor in
+            self.handleAction(identifier, port: port, pids: pids)
+        }
+    }
 }
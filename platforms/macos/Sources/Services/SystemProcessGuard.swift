@@ -0,0 +1,90 @@
+/**
+ * SystemProcessGuard.swift
+ * PortKiller
+ *
+ * Detects Apple system daemons that PortKiller cannot signal.
+ * A process counts as protected only when signalling it actually fails
+ * with EPERM, it is an Apple platform binary, and it runs as another user.
+ * Binaries the user launched from /usr/bin (ssh -L, nc -l, ruby httpd) and
+ * user-owned Apple agents stay killable.
+ *
+ * An unprivileged lsof rarely lists other users' processes, so the check
+ * mostly matters when a kill fails with EPERM and the failure needs explaining.
+ */
+
+import Foundation
+import Darwin
+
+/// Utility for identifying unkillable Apple system processes
+enum SystemProcessGuard {
+    /// C signature of csops(2)
+    private typealias CsopsFunction = @convention(c) (
+        pid_t, UInt32, UnsafeMutableRawPointer?, Int
+    ) -> Int32
+
+    /// csops(2) lives in libsystem but isn't exported in the Darwin module,
+    /// so it's resolved at runtime (dlsym with RTLD_DEFAULT)
+    nonisolated(unsafe) private static let csops: CsopsFunction? = {
+        guard let symbol = dlsym(UnsafeMutableRawPointer(bitPattern: -2), "csops") else { return nil }
+        return unsafeBitCast(symbol, to: CsopsFunction.self)
+    }()
+
+    /// csops operation returning the CS_* status flags
+    private static let csOpsStatus: UInt32 = 0
+
+    /// Binary is part of the OS (signed by Apple as a platform binary)
+    static let csPlatformBinary: UInt32 = 0x0400_0000
+
+    /// Process is restricted (SIP-protected against debugging and signals)
+    static let csRestrict: UInt32 = 0x0000_0800
+
+    /// Check whether a process is an Apple system daemon PortKiller can't signal
+    ///
+    /// - Parameters:
+    ///   - pid: Process ID to check
+    ///   - ownerUid: Effective UID the process runs as, if already known
+    ///     (the scanner reads it from the `kinfo_proc` it fetches anyway)
+    /// - Returns: True if signalling fails with EPERM and the process is an
+    ///   Apple platform binary owned by another user
+    nonisolated static func isProtected(pid: Int, ownerUid knownOwnerUid: uid_t? = nil) -> Bool {
+        // launchd and the kernel are always protected
+        if pid <= 1 { return true }
+
+        let signalPermitted = Darwin.kill(Int32(pid), 0) == 0 || errno != EPERM
+        if signalPermitted { return false }
+
+        guard let flags = codeSigningFlags(for: pid),
+              let owner = knownOwnerUid ?? ownerUid(for: pid) else { return false }
+
+        return isProtected(
+            codeSigningFlags: flags,
+            ownerUid: owner,
+            currentUid: getuid(),
+            signalPermitted: false
+        )
+    }
+
+    /// Pure protection decision, separated from the syscalls for testing
+    nonisolated static func isProtected(
+        codeSigningFlags: UInt32,
+        ownerUid: uid_t,
+        currentUid: uid_t,
+        signalPermitted: Bool
+    ) -> Bool {
+        guard !signalPermitted, ownerUid != currentUid else { return false }
+        return codeSigningFlags & (csPlatformBinary | csRestrict) != 0
+    }
+
+    /// Reads a process's code signing flags via csops(CS_OPS_STATUS)
+    nonisolated static func codeSigningFlags(for pid: Int) -> UInt32? {
+        guard let csops else { return nil }
+        var flags: UInt32 = 0
+        guard csops(pid_t(pid), csOpsStatus, &flags, MemoryLayout<UInt32>.size) == 0 else { return nil }
+        return flags
+    }
+
+    /// Reads the effective UID a process runs as via sysctl(KERN_PROC_PID)
+    nonisolated static func ownerUid(for pid: Int) -> uid_t? {
+        PortScanner.kernelProcessInfo(for: pid)?.kp_eproc.e_ucred.cr_uid
+    }
+}
@@ -28,6 +28,20 @@ final class WatchedPortsState {
     /// Tracks previous port states for change detection
     var previousPortStates: [Int: Bool] = [:]
 
+    /// Identity of the processes holding a watched port
+    struct PortOwners: Equatable {
+        /// PIDs listening on the port
+        let pids: Set<Int>
+        /// Process name of the first listener, for notification text
+        let processName: String
+    }
+
+    /// Tracks who owned each watched port on the previous scan (nil while free)
+    var previousPortOwners: [Int: PortOwners] = [:]
+
+    /// Ports whose notifications are silenced, mapped to when the silence ends
+    var mutedUntil: [Int: Date] = [:]
+
     /// Ports being watched for state changes
     var watchedPorts: [WatchedPort] {
         get { _watchedPorts }
@@ -49,6 +63,8 @@ final class WatchedPortsState {
     func toggle(_ port: Int) {
         if let idx = _watchedPorts.firstIndex(where: { $0.port == port }) {
             previousPortStates.removeValue(forKey: port)
+            previousPortOwners.removeValue(forKey: port)
+            mutedUntil.removeValue(forKey: port)
             _watchedPorts.remove(at: idx)
         } else {
             _watchedPorts.append(WatchedPort(port: port))
@@ -74,34 +90,91 @@ final class WatchedPortsState {
     func removeWatch(_ id: UUID) {
         if let w = _watchedPorts.first(where: { $0.id == id }) {
             previousPortStates.removeValue(forKey: w.port)
+            previousPortOwners.removeValue(forKey: w.port)
+            mutedUntil.removeValue(forKey: w.port)
         }
         _watchedPorts.removeAll { $0.id == id }
     }
 
+    /// Silences notifications for a watched port
+    /// - Parameters:
+    ///   - port: Port number to silence
+    ///   - duration: How long to stay silent, in seconds
+    ///   - now: Current time (injectable for tests)
+    func mute(_ port: Int, for duration: TimeInterval, now: Date = Date()) {
+        mutedUntil[port] = now.addingTimeInterval(duration)
+    }
+
+    /// Checks if notifications for a port are currently silenced
+    /// - Parameters:
+    ///   - port: Port number to check
+    ///   - now: Current time (injectable for tests)
+    func isMuted(_ port: Int, now: Date = Date()) -> Bool {
+        guard let until = mutedUntil[port] else { return false }
+        if until > now { return true }
+        mutedUntil.removeValue(forKey: port)
+        return false
+    }
+
     /// Checks watched ports for state changes and triggers notifications
+    ///
+    /// Besides start/stop transitions, this detects a port that stays bound
+    /// but moves to entirely new PIDs between scans (e.g. a crash-looping
+    /// server), which would otherwise look like "nothing changed". Owners are
+    /// compared as sets, since a port can be held by several PIDs (separate
+    /// IPv4/IPv6 listeners, SO_REUSEPORT workers) in no stable order. State is still
+    /// tracked for muted ports so no stale alert fires when the mute ends.
     /// - Parameter ports: Current active ports to check against
     func checkForChanges(ports: [PortInfo]) {
         let activePorts = Set(ports.map { $0.port })
 
         for w in _watchedPorts {
             let isActive = activePorts.contains(w.port)
+            let owners = ports.filter { $0.port == w.port }
+            let ownerPids = Set(owners.map(\.pid))
 
-            if let wasActive = previousPortStates[w.port] {
+            if let wasActive = previousPortStates[w.port], !isMuted(w.port) {
                 if wasActive && !isActive && w.notifyOnStop {
-                    notificationService.notify(
+                    notificationService.notifyWatchedPort(
+                        w.port,
+                        pids: [],
                         title: "Port \(w.port) Available",
-                        body: "Port is now free."
+                        body: "Port is now free.",
+                        canKill: false
                     )
                 } else if !wasActive && isActive && w.notifyOnStart {
-                    let name = ports.first { $0.port == w.port }?.processName ?? "Unknown"
-                    notificationService.notify(
+                    let name = owners.first?.processName ?? "Unknown"
+                    notificationService.notifyWatchedPort(
+                        w.port,
+                        pids: ownerPids.sorted(),
                         title: "Port \(w.port) In Use",
-                        body: "Used by \(name)."
+                        body: "Used by \(name).",
+                        canKill: true
+                    )
+                } else if wasActive && isActive && w.notifyOnStart,
+                          let owner = owners.first,
+                          let previous = previousPortOwners[w.port],
+                          ownerPids.isDisjoint(with: previous.pids) {
+                    notificationService.notifyWatchedPort(
+                        w.port,
+                        pids: ownerPids.sorted(),
+                        title: "Port \(w.port) Owner Changed",
+                        body: "Now used by \(owner.processName) (PID \(Self.formatPids(ownerPids))), "
+                            + "previously \(previous.processName) (PID \(Self.formatPids(previous.pids))).",
+                        canKill: true
                     )
                 }
             }
 
             previousPortStates[w.port] = isActive
+            previousPortOwners[w.port] = owners.first.map {
+                PortOwners(pids: ownerPids, processName: $0.processName)
+            }
         }
     }
+
+    /// Formats a PID set for notification text (e.g. "123, 456")
+    private static func formatPids(_ pids: Set<Int>) -> String {
+        pids.sorted().map(String.init).joined(separator: ", ")
+    }
 }
@@ -84,6 +84,8 @@ struct PortProcessInfo: View {
     let processType: ProcessType
     let isActive: Bool
     var showIcon: Bool = true
+    /// Marks an Intel binary running under Rosetta translation
+    var isTranslated: Bool = false
 
     var body: some View {
         HStack(spacing: 6) {
@@ -95,6 +97,12 @@ struct PortProcessInfo: View {
             Text(processName)
                 .lineLimit(1)
                 .foregroundStyle(isActive ? .primary : .secondary)
+            if isTranslated {
+                Image(systemName: "cpu")
+                    .font(.caption2)
+                    .foregroundStyle(.purple)
+                    .help("Intel binary running under Rosetta translation")
+            }
         }
     }
 }
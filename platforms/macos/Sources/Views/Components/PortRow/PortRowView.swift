@@ -86,7 +86,8 @@ struct PortRowView: View {
             PortProcessInfo(
                 processName: port.processName,
                 processType: port.processType,
-                isActive: port.isActive
+                isActive: port.isActive,
+                isTranslated: port.architecture == .rosetta
             )
             .frame(minWidth: 150, maxWidth: .infinity, alignment: .leading)
 
@@ -78,9 +78,7 @@ struct ProcessGroupListRow: View {
                         showConfirm = false
                         isKilling = true
                         Task {
-                            for port in group.ports {
-                                await appState.killPort(port)
-                            }
+                            await appState.killProcessGroup(group)
                         }
                     } label: {
                         Image(systemName: "checkmark.circle.fill")
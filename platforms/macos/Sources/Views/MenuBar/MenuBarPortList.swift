@@ -125,9 +125,7 @@ struct MenuBarPortList: View {
                     }
                 },
                 onKillProcess: {
-                    for port in group.ports {
-                        Task { await state.killPort(port) }
-                    }
+                    Task { await state.killProcessGroup(group) }
                 },
                 state: state
             )
@@ -19,6 +19,7 @@ struct MenuBarView: View {
     @State private var expandedProcesses: Set<String> = []
     @Default(.useTreeView) private var useTreeView
     @Default(.hideSystemProcesses) private var hideSystemProcesses
+    @Default(.showProtectedProcesses) private var showProtectedProcesses
 
     // MARK: - Cached Data (Memory Optimization)
     @State private var cachedFilteredPorts: [PortInfo] = []
@@ -31,6 +32,7 @@ struct MenuBarView: View {
         let firstPortHash: Int
         let searchText: String
         let hideSystem: Bool
+        let showProtected: Bool
     }
 
     private var groupedByProcess: [ProcessGroup] { cachedGroups }
@@ -41,7 +43,8 @@ struct MenuBarView: View {
             portsCount: state.ports.count,
             firstPortHash: state.ports.first?.hashValue ?? 0,
             searchText: searchText,
-            hideSystem: hideSystemProcesses
+            hideSystem: hideSystemProcesses,
+            showProtected: showProtectedProcesses
         )
 
         // Skip if nothing changed
@@ -62,6 +65,10 @@ struct MenuBarView: View {
             filtered = filtered.filter { $0.processType != .system }
         }
 
+        if !showProtectedProcesses {
+            filtered = filtered.filter { !$0.isSystemProtected }
+        }
+
         cachedFilteredPorts = filtered.sorted { a, b in
             let aFav = state.isFavorite(a.port)
             let bFav = state.isFavorite(b.port)
@@ -144,5 +151,6 @@ struct MenuBarView: View {
         .onChange(of: state.ports) { _, _ in updateCachedData() }
         .onChange(of: searchText) { _, _ in updateCachedData() }
         .onChange(of: hideSystemProcesses) { _, _ in updateCachedData() }
+        .onChange(of: showProtectedProcesses) { _, _ in updateCachedData() }
     }
 }
@@ -124,6 +124,34 @@ struct PortDetailView: View {
                     .clipShape(Capsule())
                 }
 
+                if port.architecture == .rosetta {
+                    HStack(spacing: 4) {
+                        Image(systemName: "cpu")
+                        Text("Rosetta")
+                    }
+                    .font(.caption)
+                    .padding(.horizontal, 8)
+                    .padding(.vertical, 4)
+                    .background(.purple.opacity(0.2))
+                    .foregroundStyle(.purple)
+                    .clipShape(Capsule())
+                    .help("Intel binary running under Rosetta translation")
+                }
+
+                if port.isSystemProtected {
+                    HStack(spacing: 4) {
+                        Image(systemName: "lock.shield.fill")
+                        Text("System Protected")
+                    }
+                    .font(.caption)
+                    .padding(.horizontal, 8)
+                    .padding(.vertical, 4)
+                    .background(.gray.opacity(0.2))
+                    .foregroundStyle(.secondary)
+                    .clipShape(Capsule())
+                    .help("macOS doesn't allow PortKiller to signal this Apple system daemon")
+                }
+
                 Spacer()
             }
         }
@@ -141,6 +169,9 @@ struct PortDetailView: View {
             DetailRow(title: "User", value: port.user)
             DetailRow(title: "File Descriptor", value: port.fd)
             DetailRow(title: "Type", value: port.processType.rawValue)
+            if let architecture = port.architecture {
+                DetailRow(title: "Architecture", value: architecture.rawValue)
+            }
         }
     }
 
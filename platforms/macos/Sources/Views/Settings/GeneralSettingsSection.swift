@@ -11,6 +11,7 @@ import Defaults
 
 struct GeneralSettingsSection: View {
     @Default(.hideSystemProcesses) private var hideSystemProcesses
+    @Default(.showProtectedProcesses) private var showProtectedProcesses
     @Default(.skipKillConfirmation) private var skipKillConfirmation
 
     var body: some View {
@@ -36,6 +37,14 @@ struct GeneralSettingsSection: View {
 
             SettingsDivider()
 
+            SettingsToggleRow(
+                title: "Show protected system processes",
+                subtitle: "Show Apple system daemons that PortKiller can't kill",
+                isOn: $showProtectedProcesses
+            )
+
+            SettingsDivider()
+
             SettingsToggleRow(
                 title: "Skip kill confirmation",
                 subtitle: "Kill processes immediately without confirmation prompt",
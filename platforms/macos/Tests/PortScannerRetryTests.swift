@@ -0,0 +1,73 @@
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for lsof run classification and scan retry backoff.
+ *
+ * These tests verify that "no listeners" (lsof exit 1, silent stderr) is not
+ * mistaken for a failure, that real errors are retried, and that the retry
+ * delay grows exponentially within the configured jitter bounds.
+ */
+struct PortScannerRetryTests {
+
+    // MARK: - Classification
+
+    @Test("Successful run returns its output")
+    func successfulRun() {
+        let output = "COMMAND PID USER\nnode 123 dev"
+        #expect(PortScanner.classifyLsofRun(status: 0, output: output, errors: "") == .output(output))
+    }
+
+    @Test("Exit 1 with no output and no errors means no listeners")
+    func noListeners() {
+        #expect(PortScanner.classifyLsofRun(status: 1, output: "", errors: "") == .output(""))
+        #expect(PortScanner.classifyLsofRun(status: 1, output: "", errors: "\n") == .output(""))
+    }
+
+    @Test("Exit 1 with an error message is a transient failure")
+    func errorIsTransient() {
+        let errors = "lsof: can't allocate memory\n"
+        #expect(PortScanner.classifyLsofRun(status: 1, output: "", errors: errors) == .transientFailure)
+    }
+
+    @Test("Unexpected exit status without output is a transient failure")
+    func unexpectedStatusIsTransient() {
+        #expect(PortScanner.classifyLsofRun(status: 2, output: "", errors: "") == .transientFailure)
+    }
+
+    @Test("Partial output is kept despite a non-zero exit")
+    func partialOutputKept() {
+        let output = "COMMAND PID USER\nnode 123 dev"
+        let result = PortScanner.classifyLsofRun(status: 1, output: output, errors: "lsof: status error")
+        #expect(result == .output(output))
+    }
+
+    // MARK: - Backoff
+
+    @Test("Delay doubles per attempt without jitter")
+    func exponentialDelay() {
+        let policy = ScanRetryPolicy.default
+        #expect(policy.delay(afterAttempt: 1, random: 0) == .milliseconds(100))
+        #expect(policy.delay(afterAttempt: 2, random: 0) == .milliseconds(200))
+        #expect(policy.delay(afterAttempt: 3, random: 0) == .milliseconds(400))
+    }
+
+    @Test("Jitter stays within the configured fraction")
+    func jitterBounds() {
+        let policy = ScanRetryPolicy.default
+        #expect(policy.delay(afterAttempt: 2, random: -1) == .milliseconds(100))
+        #expect(policy.delay(afterAttempt: 2, random: 1) == .milliseconds(300))
+
+        for _ in 0..<100 {
+            let delay = policy.delay(afterAttempt: 2)
+            #expect(delay >= .milliseconds(100) && delay <= .milliseconds(300))
+        }
+    }
+
+    @Test("Out-of-range jitter samples are clamped")
+    func jitterClamped() {
+        let policy = ScanRetryPolicy.default
+        #expect(policy.delay(afterAttempt: 1, random: 5) == .milliseconds(150))
+        #expect(policy.delay(afterAttempt: 1, random: -5) == .milliseconds(50))
+    }
+}
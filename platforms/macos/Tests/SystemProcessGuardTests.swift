@@ -0,0 +1,79 @@
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for protected system process detection.
+ *
+ * These tests verify that SystemProcessGuard only treats a process as
+ * unkillable when signalling it is denied, it is an Apple platform binary,
+ * and it runs as another user — so user-launched /usr/bin tools and
+ * user-owned Apple agents stay killable.
+ */
+struct SystemProcessGuardTests {
+
+    private let currentUser: uid_t = 501
+    private let root: uid_t = 0
+
+    @Test("Root-owned platform daemon that rejects signals is protected")
+    func rootPlatformDaemonProtected() {
+        #expect(SystemProcessGuard.isProtected(
+            codeSigningFlags: SystemProcessGuard.csPlatformBinary,
+            ownerUid: root,
+            currentUid: currentUser,
+            signalPermitted: false
+        ))
+    }
+
+    @Test("Restricted daemon owned by another user is protected")
+    func restrictedDaemonProtected() {
+        #expect(SystemProcessGuard.isProtected(
+            codeSigningFlags: SystemProcessGuard.csRestrict,
+            ownerUid: 200,
+            currentUid: currentUser,
+            signalPermitted: false
+        ))
+    }
+
+    @Test("User-launched /usr/bin tools are not protected")
+    func userLaunchedPlatformBinaryNotProtected() {
+        // e.g. `/usr/bin/ssh -L` or `/usr/bin/nc -l` started by the user
+        #expect(!SystemProcessGuard.isProtected(
+            codeSigningFlags: SystemProcessGuard.csPlatformBinary,
+            ownerUid: currentUser,
+            currentUid: currentUser,
+            signalPermitted: true
+        ))
+    }
+
+    @Test("User-owned Apple agents are not protected")
+    func userOwnedAgentNotProtected() {
+        // e.g. rapportd / ControlCenter on :5000 and :7000
+        #expect(!SystemProcessGuard.isProtected(
+            codeSigningFlags: SystemProcessGuard.csPlatformBinary | SystemProcessGuard.csRestrict,
+            ownerUid: currentUser,
+            currentUid: currentUser,
+            signalPermitted: false
+        ))
+    }
+
+    @Test("Signallable processes are never protected")
+    func signallableNotProtected() {
+        #expect(!SystemProcessGuard.isProtected(
+            codeSigningFlags: SystemProcessGuard.csPlatformBinary,
+            ownerUid: root,
+            currentUid: currentUser,
+            signalPermitted: true
+        ))
+    }
+
+    @Test("Third-party processes of other users are not protected")
+    func thirdPartyNotProtected() {
+        // e.g. a root-run Homebrew service: denied, but not an Apple daemon
+        #expect(!SystemProcessGuard.isProtected(
+            codeSigningFlags: 0,
+            ownerUid: root,
+            currentUid: currentUser,
+            signalPermitted: false
+        ))
+    }
+}
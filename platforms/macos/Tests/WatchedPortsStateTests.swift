@@ -0,0 +1,139 @@
+import Foundation
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for watched port change detection and notification muting.
+ *
+ * These tests drive WatchedPortsState with in-memory storage and a
+ * recording notification service, so transitions can be checked without
+ * touching UserDefaults or Notification Center.
+ */
+@MainActor
+struct WatchedPortsStateTests {
+
+    /// A watched-port notification captured by the fake service
+    struct SentNotification: Equatable {
+        let port: Int
+        let pids: [Int]
+        let title: String
+        let canKill: Bool
+    }
+
+    /// Notification service that records watched-port notifications
+    final class RecordingNotificationService: NotificationServiceProtocol {
+        var sent: [SentNotification] = []
+        var bodies: [String] = []
+
+        func setup() {}
+
+        func notify(title: String, body: String) {}
+
+        func notifyWatchedPort(_ port: Int, pids: [Int], title: String, body: String, canKill: Bool) {
+            sent.append(SentNotification(port: port, pids: pids, title: title, canKill: canKill))
+            bodies.append(body)
+        }
+
+        func requestPermission() async -> Bool { true }
+    }
+
+    /// Storage that keeps nothing
+    struct InMemoryWatchedPortsStorage: WatchedPortsStorageProtocol {
+        func load() -> [WatchedPort] { [] }
+        func save(_ watchedPorts: [WatchedPort]) {}
+    }
+
+    private let notifier = RecordingNotificationService()
+
+    private func makeState(watching port: Int) -> WatchedPortsState {
+        let state = WatchedPortsState(storage: InMemoryWatchedPortsStorage(), notificationService: notifier)
+        state.toggle(port)
+        return state
+    }
+
+    private func listener(port: Int, pid: Int, processName: String = "node") -> PortInfo {
+        PortInfo.active(
+            port: port,
+            pid: pid,
+            processName: processName,
+            address: "127.0.0.1",
+            user: "dev",
+            command: "node server.js",
+            fd: "20u"
+        )
+    }
+
+    // MARK: - Muting
+
+    @Test("Mute expires after its duration")
+    func muteExpires() {
+        let state = makeState(watching: 3000)
+        let start = Date()
+
+        state.mute(3000, for: 3600, now: start)
+
+        #expect(state.isMuted(3000, now: start.addingTimeInterval(60)))
+        #expect(!state.isMuted(3000, now: start.addingTimeInterval(3601)))
+        #expect(state.mutedUntil[3000] == nil)
+    }
+
+    @Test("Unmuted ports are not muted")
+    func unmutedPort() {
+        let state = makeState(watching: 3000)
+        #expect(!state.isMuted(3000))
+    }
+
+    @Test("No notification while muted, but state is still tracked")
+    func mutedPortStaysSilent() {
+        let state = makeState(watching: 3000)
+        state.checkForChanges(ports: [])
+
+        state.mute(3000, for: 3600)
+        state.checkForChanges(ports: [listener(port: 3000, pid: 123)])
+
+        #expect(notifier.sent.isEmpty)
+        #expect(state.previousPortStates[3000] == true)
+    }
+
+    // MARK: - Transitions
+
+    @Test("Port start notification names the owning PID")
+    func startNotificationCarriesPid() {
+        let state = makeState(watching: 3000)
+        state.checkForChanges(ports: [])
+
+        state.checkForChanges(ports: [listener(port: 3000, pid: 123)])
+
+        #expect(notifier.sent == [
+            SentNotification(port: 3000, pids: [123], title: "Port 3000 In Use", canKill: true)
+        ])
+    }
+
+    @Test("Owner change fires when the port moves to new PIDs")
+    func ownerChangeNotification() {
+        let state = makeState(watching: 3000)
+        state.checkForChanges(ports: [])
+        state.checkForChanges(ports: [listener(port: 3000, pid: 123)])
+        notifier.sent.removeAll()
+
+        state.checkForChanges(ports: [listener(port: 3000, pid: 456, processName: "python3")])
+
+        #expect(notifier.sent == [
+            SentNotification(port: 3000, pids: [456], title: "Port 3000 Owner Changed", canKill: true)
+        ])
+        #expect(notifier.bodies.last == "Now used by python3 (PID 456), previously node (PID 123).")
+    }
+
+    @Test("Reordered owners of a shared port don't look like an owner change")
+    func reorderedOwnersAreStable() {
+        let state = makeState(watching: 3000)
+        state.checkForChanges(ports: [])
+        state.checkForChanges(ports: [listener(port: 3000, pid: 123), listener(port: 3000, pid: 456)])
+        notifier.sent.removeAll()
+
+        state.checkForChanges(ports: [listener(port: 3000, pid: 456), listener(port: 3000, pid: 123)])
+        state.checkForChanges(ports: [listener(port: 3000, pid: 456)])
+
+        #expect(notifier.sent.isEmpty)
+    }
+}
@@ -27,6 +27,19 @@ public partial class App : Application
         Services = services.BuildServiceProvider();
     }
 
+    protected override void OnStartup(StartupEventArgs e)
+    {
+        // Cleanup hook for the zip distribution: remove the toast registration before deleting the app folder
+        if (e.Args.Contains("--uninstall", StringComparer.OrdinalIgnoreCase))
+        {
+            NotificationService.Uninstall();
+            Shutdown();
+            return;
+        }
+
+        base.OnStartup(e);
+    }
+
     private void ConfigureServices(IServiceCollection services)
     {
         // Services
@@ -5,6 +5,7 @@ using System.Windows.Controls;
 using System.Windows.Input;
 using Microsoft.Extensions.DependencyInjection;
 using PortKiller.Models;
+using PortKiller.Services;
 using PortKiller.ViewModels;
 using PortKiller.Helpers;
 
@@ -32,6 +33,14 @@ public partial class MainWindow : Window
         // Initialize system tray icon
         InitializeTrayIcon();
         
+        // Clicking a watched-port toast brings the window back from the tray
+        NotificationService.Instance.Activated += (s, e) => Dispatcher.InvokeAsync(() =>
+        {
+            Show();
+            WindowState = WindowState.Normal;
+            Activate();
+        });
+
         // Ensure window is visible and activated on startup
         Loaded += (s, e) =>
         {
@@ -112,6 +121,56 @@ public partial class MainWindow : Window
         await _viewModel.InitializeAsync();
         UpdateUI();
 
+        _viewModel.KillRefused += (s, message) =>
+        {
+            Dispatcher.Invoke(() =>
+                MessageBox.Show(message, "Kill Refused", MessageBoxButton.OK, MessageBoxImage.Warning));
+        };
+
+        _viewModel.KillFailed += (s, message) =>
+        {
+            Dispatcher.Invoke(() =>
+                MessageBox.Show(message, "Stop Failed", MessageBoxButton.OK, MessageBoxImage.Warning));
+        };
+
+        // Both events are raised from commands running on the UI thread
+        _viewModel.ElevationRequired += async (s, port) =>
+        {
+            var dialog = new ConfirmDialog(
+                $"PortKiller needs administrator privileges to stop {port.ProcessName} on port {port.Port}.",
+                $"PID: {port.Pid}\nUser: {port.User}\n\nRetry as administrator? Windows will ask for confirmation.",
+                "Administrator Required")
+            {
+                Owner = this
+            };
+
+            dialog.ShowDialog();
+
+            if (dialog.Result)
+            {
+                await _viewModel.KillElevatedCommand.ExecuteAsync(port);
+            }
+        };
+
+        _viewModel.BatchElevationRequired += async (s, ports) =>
+        {
+            var processes = string.Join("\n", ports.Select(p => $"{p.ProcessName} (PID {p.Pid}) on port {p.Port}"));
+            var dialog = new ConfirmDialog(
+                $"PortKiller needs administrator privileges to stop {ports.Count} process(es).",
+                $"{processes}\n\nRetry all as administrator? Windows will ask for confirmation once.",
+                "Administrator Required")
+            {
+                Owner = this
+            };
+
+            dialog.ShowDialog();
+
+            if (dialog.Result)
+            {
+                await _viewModel.KillAllElevatedCommand.ExecuteAsync(ports);
+            }
+        };
+
         // Subscribe to property changes
         _viewModel.PropertyChanged += (s, e) =>
         {
@@ -230,7 +289,7 @@ public partial class MainWindow : Window
         DetailPanel.Visibility = Visibility.Visible;
 
         DetailPort.Text = port.DisplayPort;
-        DetailProcess.Text = port.ProcessName;
+        DetailProcess.Text = DescribeProcess(port);
         DetailPid.Text = port.Pid.ToString();
         DetailAddress.Text = port.Address;
         DetailUser.Text = port.User;
@@ -251,13 +310,8 @@ public partial class MainWindow : Window
     {
         if (sender is Button button && button.Tag is PortInfo port)
         {
-            var dialog = new ConfirmDialog(
-                $"Are you sure you want to kill the process on port {port.Port}?",
-                $"Process: {port.ProcessName}\nPID: {port.Pid}\n\nThis action cannot be undone.",
-                "Kill Process")
-            {
-                Owner = this
-            };
+            var dialog = CreateKillDialog(port);
+            dialog.Owner = this;
             
             dialog.ShowDialog();
 
@@ -268,6 +322,52 @@ public partial class MainWindow : Window
         }
     }
 
+    internal static ConfirmDialog CreateKillDialog(PortInfo port)
+    {
+        if (port.IsPortProxy)
+        {
+            var rules = string.Join("\n", port.ProxyRules.Select(r =>
+                $"{r.Kind} {r.ListenAddress}:{r.ListenPort} → {r.DisplayTarget}"));
+            var noun = port.ProxyRules.Count == 1 ? "rule" : $"{port.ProxyRules.Count} rules";
+            return new ConfirmDialog(
+                $"Port {port.Port} is held by portproxy forwarding to {port.ProxyTargets}. Remove the {noun}?",
+                $"{rules}\n\nAll portproxy rules on this port will be removed. This requires administrator privileges.",
+                "Remove Port Proxy");
+        }
+
+        if (port.IsWslForward)
+        {
+            return new ConfirmDialog(
+                $"Port {port.Port} is forwarded from WSL2 by wslrelay. Kill the relay?",
+                $"Process: {port.ProcessName}\nPID: {port.Pid}\n\nwslrelay forwards every localhost port of the WSL distribution, so all of them stop, not just {port.Port}. To free only this port, stop the server inside WSL instead.",
+                "Kill WSL Relay");
+        }
+
+        if (port.IsService)
+        {
+            return new ConfirmDialog(
+                $"Port {port.Port} belongs to the Windows service \"{port.ServiceName}\". Stop the service?",
+                $"Process: {port.ProcessName}\nPID: {port.Pid}\n\nThe service will be stopped through the Service Control Manager instead of terminating its process.",
+                "Stop Service");
+        }
+
+        return new ConfirmDialog(
+            $"Are you sure you want to kill the process on port {port.Port}?",
+            $"Process: {port.ProcessName}\nPID: {port.Pid}\n\nThis action cannot be undone.",
+            "Kill Process");
+    }
+
+    private static string DescribeProcess(PortInfo port)
+    {
+        if (port.IsPortProxy)
+            return $"{port.ProcessName} (portproxy → {port.ProxyTargets})";
+        if (port.IsWslForward)
+            return $"{port.ProcessName} (WSL2 forwarded port)";
+        if (port.IsService)
+            return $"{port.ProcessName} (service: {port.ServiceName})";
+        return port.ProcessName;
+    }
+
     private void FavoriteButton_Click(object sender, RoutedEventArgs e)
     {
         if (_viewModel.SelectedPort != null)
@@ -372,17 +472,8 @@ public partial class MainWindow : Window
 
         if (dialog.Result)
         {
-            foreach (var port in _viewModel.Ports.ToList())
-            {
-                try
-                {
-                    await _viewModel.KillProcessCommand.ExecuteAsync(port);
-                }
-                catch (Exception ex)
-                {
-                    System.Diagnostics.Debug.WriteLine($"Failed to kill process on port {port.Port}: {ex.Message}");
-                }
-            }
+            // Skips services, portproxy rules, WSL2 forwards and protected system processes
+            await _viewModel.KillAllCommand.ExecuteAsync(null);
         }
     }
 
@@ -577,14 +668,9 @@ public partial class MainWindow : Window
                         var menuItem = s as MenuItem;
                         if (menuItem?.Tag is PortInfo portInfo)
                         {
-                            var dialog = new ConfirmDialog(
-                                $"Kill process on port {portInfo.Port}?",
-                                $"Process: {portInfo.ProcessName}\nPID: {portInfo.Pid}",
-                                "Kill Process")
-                            {
-                                Owner = this
-                            };
-                            
+                            var dialog = CreateKillDialog(portInfo);
+                            dialog.Owner = this;
+
                             dialog.ShowDialog();
 
                             if (dialog.Result)
@@ -162,12 +162,34 @@ public partial class MiniPortKillerWindow : Window
         UpdatePortList();
     }
 
-    private void KillSinglePort_Click(object sender, MouseButtonEventArgs e)
+    private async void KillSinglePort_Click(object sender, MouseButtonEventArgs e)
     {
         if (sender is Button btn && btn.Tag is PortInfo port)
         {
             // Stop event propagation
             e.Handled = true;
+
+            // Forwarded ports need the full explanation: removing a portproxy rule
+            // prompts for UAC, and killing wslrelay drops every WSL2 forward
+            if (port.IsPortProxy || port.IsWslForward)
+            {
+                _isProcessingAction = true;
+                try
+                {
+                    var dialog = MainWindow.CreateKillDialog(port);
+                    dialog.Owner = this;
+                    dialog.ShowDialog();
+
+                    if (dialog.Result)
+                        await KillPortAsync(port);
+                }
+                finally
+                {
+                    _isProcessingAction = false;
+                }
+                return;
+            }
+
             port.IsConfirmingKill = true;
         }
     }
@@ -177,20 +199,25 @@ public partial class MiniPortKillerWindow : Window
         if (sender is Button btn && btn.Tag is PortInfo port)
         {
             port.IsConfirmingKill = false;
-            port.IsKilling = true;
+            await KillPortAsync(port);
+        }
+    }
 
-            try
-            {
-                await _viewModel.KillProcessCommand.ExecuteAsync(port);
-            }
-            finally
+    private async Task KillPortAsync(PortInfo port)
+    {
+        port.IsKilling = true;
+
+        try
+        {
+            await _viewModel.KillProcessCommand.ExecuteAsync(port);
+        }
+        finally
+        {
+            // The port will be removed from the list by the ViewModel refresh
+            // If it fails, we should reset the state
+            if (_viewModel.Ports.Contains(port))
             {
-                // The port will be removed from the list by the ViewModel refresh
-                // If it fails, we should reset the state
-                if (_viewModel.Ports.Contains(port))
-                {
-                    port.IsKilling = false;
-                }
+                port.IsKilling = false;
             }
         }
     }
@@ -222,12 +249,8 @@ public partial class MiniPortKillerWindow : Window
 
             if (dialog.Result)
             {
-                // Create a copy of the list to avoid collection modification errors
-                var portsToKill = _viewModel.Ports.ToList();
-                foreach (var port in portsToKill)
-                {
-                    await _viewModel.KillProcessCommand.ExecuteAsync(port);
-                }
+                // Skips services, portproxy rules, WSL2 forwards and protected system processes
+                await _viewModel.KillAllCommand.ExecuteAsync(null);
                 
                 // The auto-refresh in MainViewModel will update the UI automatically
                 // via the CollectionChanged event subscription
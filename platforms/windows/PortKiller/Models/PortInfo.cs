@@ -1,4 +1,6 @@
 using System;
+using System.Collections.Generic;
+using System.Linq;
 using System.ComponentModel;
 using System.Runtime.CompilerServices;
 
@@ -55,6 +57,57 @@ public class PortInfo : INotifyPropertyChanged
     /// </summary>
     public bool IsActive { get; init; }
 
+    /// <summary>
+    /// Name of the Windows service hosted by the owning process, if any.
+    /// Only set when the process hosts exactly one service.
+    /// </summary>
+    public string? ServiceName { get; init; }
+
+    /// <summary>
+    /// Whether the owning process is a Windows service
+    /// </summary>
+    public bool IsService => !string.IsNullOrEmpty(ServiceName);
+
+    /// <summary>
+    /// The netsh portproxy rules listening on this port (one per address/family), if any
+    /// </summary>
+    public IReadOnlyList<PortProxyRule> ProxyRules { get; init; } = Array.Empty<PortProxyRule>();
+
+    /// <summary>
+    /// Whether this port is held by netsh portproxy rules rather than an application
+    /// </summary>
+    public bool IsPortProxy => ProxyRules.Count > 0;
+
+    /// <summary>
+    /// Forwarding targets of all portproxy rules on this port (e.g., "172.20.1.2:8080")
+    /// </summary>
+    public string ProxyTargets => string.Join(", ", ProxyRules.Select(r => r.DisplayTarget).Distinct());
+
+    /// <summary>
+    /// Whether this port is auto-forwarded from a WSL2 distribution (owned by wslrelay)
+    /// </summary>
+    public bool IsWslForward => ProcessName.Equals("wslrelay", StringComparison.OrdinalIgnoreCase);
+
+    /// <summary>
+    /// Badge marking forwarded ports in the port list ("portproxy", "WSL2"), or null for applications
+    /// </summary>
+    public string? ForwardBadge => IsPortProxy ? "portproxy" : IsWslForward ? "WSL2" : null;
+
+    /// <summary>
+    /// Whether the port list shows a forwarding badge for this port
+    /// </summary>
+    public bool HasForwardBadge => ForwardBadge != null;
+
+    /// <summary>
+    /// Question shown in the inline kill confirmation (e.g., "Kill node?", "Stop service W3SVC?")
+    /// </summary>
+    public string KillPrompt => IsService ? $"Stop service {ServiceName}?" : $"Kill {ProcessName}?";
+
+    /// <summary>
+    /// Label of the inline confirm button ("Kill", or "Stop" for services)
+    /// </summary>
+    public string KillButtonLabel => IsService ? "Stop" : "Kill";
+
     /// <summary>
     /// UI State: Showing confirmation buttons
     /// </summary>
@@ -120,7 +173,9 @@ public class PortInfo : INotifyPropertyChanged
         string processName,
         string address,
         string user,
-        string command) => new()
+        string command,
+        string? serviceName = null,
+        IReadOnlyList<PortProxyRule>? proxyRules = null) => new()
     {
         Port = port,
         Pid = pid,
@@ -128,7 +183,9 @@ public class PortInfo : INotifyPropertyChanged
         Address = address,
         User = user,
         Command = command,
-        IsActive = true
+        IsActive = true,
+        ServiceName = serviceName,
+        ProxyRules = proxyRules ?? Array.Empty<PortProxyRule>()
     };
 
     protected virtual void OnPropertyChanged([CallerMemberName] string? propertyName = null)
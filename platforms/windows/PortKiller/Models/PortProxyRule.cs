@@ -0,0 +1,39 @@
+namespace PortKiller.Models;
+
+/// <summary>
+/// A `netsh interface portproxy` forwarding rule.
+/// The listening socket is owned by the IP Helper service (svchost),
+/// so these ports have no obvious owning process in the port list.
+/// </summary>
+public record PortProxyRule
+{
+    /// <summary>
+    /// Rule family as used by netsh (v4tov4, v4tov6, v6tov4, v6tov6)
+    /// </summary>
+    public string Kind { get; init; } = "v4tov4";
+
+    /// <summary>
+    /// Address the proxy listens on (e.g., "0.0.0.0", "*")
+    /// </summary>
+    public string ListenAddress { get; init; } = string.Empty;
+
+    /// <summary>
+    /// Port the proxy listens on
+    /// </summary>
+    public int ListenPort { get; init; }
+
+    /// <summary>
+    /// Address connections are forwarded to (e.g., a WSL2 VM IP)
+    /// </summary>
+    public string ConnectAddress { get; init; } = string.Empty;
+
+    /// <summary>
+    /// Port connections are forwarded to
+    /// </summary>
+    public int ConnectPort { get; init; }
+
+    /// <summary>
+    /// Forwarding target for display (e.g., "172.20.1.2:8080")
+    /// </summary>
+    public string DisplayTarget => $"{ConnectAddress}:{ConnectPort}";
+}
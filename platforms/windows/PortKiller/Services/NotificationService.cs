@@ -1,10 +1,13 @@
 using System;
+using CommunityToolkit.WinUI.Notifications;
 
 namespace PortKiller.Services;
 
 /// <summary>
 /// Service for sending Windows notifications for watched port events.
-/// Uses simple message boxes for now (can be enhanced with Windows 10/11 toast notifications later)
+/// Uses Windows 10/11 toast notifications. For the unpackaged app the toolkit
+/// registers an AUMID under HKCU on first use (no Start menu shortcut is created),
+/// so toasts show as PortKiller and persist in Action Center.
 /// </summary>
 public class NotificationService
 {
@@ -13,6 +16,11 @@ public class NotificationService
 
     private bool _isInitialized;
 
+    /// <summary>
+    /// Raised when the user clicks a toast. Fires on a background thread.
+    /// </summary>
+    public event EventHandler? Activated;
+
     private NotificationService()
     {
     }
@@ -22,7 +30,11 @@ public class NotificationService
     /// </summary>
     public void Initialize()
     {
+        if (_isInitialized)
+            return;
+
         _isInitialized = true;
+        ToastNotificationManagerCompat.OnActivated += _ => Activated?.Invoke(this, EventArgs.Empty);
     }
 
     /// <summary>
@@ -35,9 +47,7 @@ public class NotificationService
 
         try
         {
-            // For now, we'll just write to debug output
-            // You can enhance this with Windows.UI.Notifications or other notification libraries
-            System.Diagnostics.Debug.WriteLine($"✅ Port {port} started - Process: {processName}");
+            ShowToast($"Port {port} In Use", $"Used by {processName}.");
         }
         catch
         {
@@ -55,7 +65,28 @@ public class NotificationService
 
         try
         {
-            System.Diagnostics.Debug.WriteLine($"❌ Port {port} stopped");
+            ShowToast($"Port {port} Available", "Port is now free.");
+        }
+        catch
+        {
+            // Silently fail
+        }
+    }
+
+    /// <summary>
+    /// Send notification when a watched port stays bound but moves to new owning PIDs
+    /// </summary>
+    public void NotifyPortOwnerChanged(
+        int port, string oldProcessName, IEnumerable<int> oldPids, string newProcessName, IEnumerable<int> newPids)
+    {
+        if (!_isInitialized)
+            return;
+
+        try
+        {
+            ShowToast($"Port {port} Owner Changed",
+                $"Now used by {newProcessName} (PID {string.Join(", ", newPids.Order())}), " +
+                $"previously {oldProcessName} (PID {string.Join(", ", oldPids.Order())}).");
         }
         catch
         {
@@ -73,7 +104,7 @@ public class NotificationService
 
         try
         {
-            System.Diagnostics.Debug.WriteLine($"📢 {title}: {message}");
+            ShowToast(title, message);
         }
         catch
         {
@@ -85,4 +116,24 @@ public class NotificationService
     {
         _isInitialized = false;
     }
+
+    /// <summary>
+    /// Removes the toast registration (HKCU AUMID entry) and clears PortKiller's
+    /// notifications from Action Center. Run via `PortKiller.exe --uninstall`.
+    /// </summary>
+    public static void Uninstall()
+    {
+        ToastNotificationManagerCompat.Uninstall();
+    }
+
+    /// <summary>
+    /// Show a two-line toast notification
+    /// </summary>
+    private static void ShowToast(string title, string message)
+    {
+        new ToastContentBuilder()
+            .AddText(title)
+            .AddText(message)
+            .Show();
+    }
 }
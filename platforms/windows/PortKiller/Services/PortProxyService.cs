@@ -0,0 +1,80 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Runtime.Versioning;
+using Microsoft.Win32;
+using PortKiller.Models;
+
+namespace PortKiller.Services;
+
+/// <summary>
+/// Reads `netsh interface portproxy` rules.
+/// Rules are read from the registry (where netsh stores them) rather than by
+/// parsing netsh output, which is localized and would spawn a process per scan.
+/// </summary>
+[SupportedOSPlatform("windows")]
+public class PortProxyService
+{
+    private const string PortProxyKey = @"SYSTEM\CurrentControlSet\Services\PortProxy";
+    private static readonly string[] RuleKinds = ["v4tov4", "v4tov6", "v6tov4", "v6tov6"];
+
+    /// <summary>
+    /// Gets all configured portproxy rules.
+    /// Registry values are stored as "listenaddress/listenport" = "connectaddress/connectport".
+    /// </summary>
+    public List<PortProxyRule> GetRules()
+    {
+        var rules = new List<PortProxyRule>();
+
+        try
+        {
+            foreach (var kind in RuleKinds)
+            {
+                using var key = Registry.LocalMachine.OpenSubKey($@"{PortProxyKey}\{kind}\tcp");
+                if (key == null)
+                    continue;
+
+                foreach (var valueName in key.GetValueNames())
+                {
+                    if (key.GetValue(valueName) is not string target)
+                        continue;
+
+                    if (!TryParseEndpoint(valueName, out var listenAddress, out var listenPort) ||
+                        !TryParseEndpoint(target, out var connectAddress, out var connectPort))
+                        continue;
+
+                    rules.Add(new PortProxyRule
+                    {
+                        Kind = kind,
+                        ListenAddress = listenAddress,
+                        ListenPort = listenPort,
+                        ConnectAddress = connectAddress,
+                        ConnectPort = connectPort
+                    });
+                }
+            }
+        }
+        catch (Exception ex)
+        {
+            Debug.WriteLine($"Error reading portproxy rules: {ex.Message}");
+        }
+
+        return rules;
+    }
+
+    /// <summary>
+    /// Parses an "address/port" registry endpoint
+    /// </summary>
+    private static bool TryParseEndpoint(string value, out string address, out int port)
+    {
+        address = string.Empty;
+        port = 0;
+
+        var separator = value.LastIndexOf('/');
+        if (separator <= 0)
+            return false;
+
+        address = value[..separator];
+        return int.TryParse(value[(separator + 1)..], out port);
+    }
+}
@@ -18,6 +18,8 @@ namespace PortKiller.Services;
 [SupportedOSPlatform("windows")]
 public class PortScannerService
 {
+    private readonly PortProxyService _portProxyService = new();
+
     // Win32 API imports for TCP table
     [DllImport("iphlpapi.dll", SetLastError = true)]
     private static extern uint GetExtendedTcpTable(
@@ -110,6 +112,8 @@ public class PortScannerService
             {
                 var ports = new List<PortInfo>();
                 var processCache = new Dictionary<int, (string name, string command, string user)>();
+                var services = GetServicesByPid();
+                var proxyRules = _portProxyService.GetRules().ToLookup(r => r.ListenPort);
 
                 // Scan IPv4 ports
                 var tcpRows = GetAllTcpConnections();
@@ -136,7 +140,9 @@ public class PortScannerService
                             processName: processInfo.name,
                             address: address,
                             user: processInfo.user,
-                            command: processInfo.command);
+                            command: processInfo.command,
+                            serviceName: services.GetValueOrDefault(pid),
+                            proxyRules: GetProxyRules(proxyRules, port, processInfo.name));
                         
                         // Explicitly set IsKilling to false when creating new Active ports
                         portInfo.IsKilling = false;
@@ -176,7 +182,9 @@ public class PortScannerService
                             processName: processInfo.name,
                             address: address,
                             user: processInfo.user,
-                            command: processInfo.command);
+                            command: processInfo.command,
+                            serviceName: services.GetValueOrDefault(pid),
+                            proxyRules: GetProxyRules(proxyRules, port, processInfo.name));
                         
                         portInfo.IsKilling = false;
                         portInfo.IsConfirmingKill = false;
@@ -378,6 +386,54 @@ public class PortScannerService
         return null;
     }
 
+    /// <summary>
+    /// Matches a listener to its portproxy rules. Proxy listeners are always
+    /// owned by the IP Helper service, which runs inside svchost.
+    /// All rules on the port are returned (e.g., v4tov4 on 0.0.0.0 plus v6tov4 on ::),
+    /// since the port list shows a single row per port and PID.
+    /// </summary>
+    private static List<PortProxyRule> GetProxyRules(ILookup<int, PortProxyRule> rules, int port, string processName)
+    {
+        if (!processName.Equals("svchost", StringComparison.OrdinalIgnoreCase))
+            return new List<PortProxyRule>();
+
+        return rules[port].ToList();
+    }
+
+    /// <summary>
+    /// Maps PIDs to the Windows service they host using WMI (Win32_Service).
+    /// Shared hosts (svchost running several services) are skipped, since
+    /// stopping one of their services would not reliably free the port.
+    /// </summary>
+    private Dictionary<int, string> GetServicesByPid()
+    {
+        var result = new Dictionary<int, string>();
+
+        try
+        {
+            using var searcher = new System.Management.ManagementObjectSearcher(
+                "SELECT Name, ProcessId FROM Win32_Service WHERE ProcessId <> 0");
+            using var objects = searcher.Get();
+
+            var grouped = objects
+                .Cast<System.Management.ManagementObject>()
+                .GroupBy(obj => Convert.ToInt32(obj["ProcessId"]));
+
+            foreach (var group in grouped)
+            {
+                var names = group.Select(obj => obj["Name"]?.ToString()).ToList();
+                if (names.Count == 1 && names[0] is string name)
+                    result[group.Key] = name;
+            }
+        }
+        catch (Exception ex)
+        {
+            Debug.WriteLine($"Error querying services: {ex.Message}");
+        }
+
+        return result;
+    }
+
     /// <summary>
     /// Gets the owner (username) of a process
     /// </summary>
@@ -1,7 +1,11 @@
 using System;
+using System.Collections.Generic;
+using System.ComponentModel;
 using System.Diagnostics;
+using System.Linq;
 using System.Runtime.Versioning;
 using System.Threading.Tasks;
+using PortKiller.Models;
 
 namespace PortKiller.Services;
 
@@ -12,6 +16,51 @@ namespace PortKiller.Services;
 [SupportedOSPlatform("windows")]
 public class ProcessKillerService
 {
+    /// <summary>
+    /// Core Windows processes that must never be terminated.
+    /// Killing any of these crashes the session or bluescreens the machine.
+    /// </summary>
+    private static readonly HashSet<string> ProtectedProcessNames = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "System", "Idle", "Registry", "Memory Compression", "Secure System",
+        "smss", "csrss", "wininit", "winlogon", "services", "lsass", "LsaIso",
+        "svchost", "dwm", "fontdrvhost", "MsMpEng"
+    };
+
+    /// <summary>
+    /// Core Windows services that must never be stopped.
+    /// On split-svchost systems each runs in its own svchost, so the port list
+    /// resolves them as services rather than as a protected svchost process.
+    /// </summary>
+    private static readonly HashSet<string> ProtectedServiceNames = new(StringComparer.OrdinalIgnoreCase)
+    {
+        "RpcSs", "RpcEptMapper", "DcomLaunch", "LSM", "SamSs", "PlugPlay", "Power",
+        "EventLog", "Winmgmt", "CryptSvc", "Dnscache", "Dhcp", "nsi", "iphlpsvc",
+        "LanmanServer", "LanmanWorkstation", "BFE", "MpsSvc", "WinDefend",
+        "CDPSvc", "TermService"
+    };
+
+    private const int ErrorAccessDenied = 5;
+    private const int ErrorCancelled = 1223;
+    private const int ErrorServiceNotActive = 1062;
+
+    /// <summary>
+    /// Whether a process is a critical system process that PortKiller refuses to kill.
+    /// PIDs 0 (Idle) and 4 (System) are always protected.
+    /// </summary>
+    public static bool IsProtectedProcess(int pid, string processName)
+    {
+        return pid <= 4 || ProtectedProcessNames.Contains(processName);
+    }
+
+    /// <summary>
+    /// Whether a Windows service is a core system service that PortKiller refuses to stop.
+    /// </summary>
+    public static bool IsProtectedService(string serviceName)
+    {
+        return ProtectedServiceNames.Contains(serviceName);
+    }
+
     /// <summary>
     /// Kills a process by PID.
     /// Windows doesn't have SIGTERM equivalent, so this terminates immediately.
@@ -23,6 +72,7 @@ public class ProcessKillerService
             try
             {
                 using var process = Process.GetProcessById(pid);
+                EnsureNotProtected(process);
                 
                 if (!force)
                 {
@@ -40,6 +90,14 @@ public class ProcessKillerService
                 process.Kill(entireProcessTree: true);
                 return true;
             }
+            catch (ProtectedProcessException)
+            {
+                throw;
+            }
+            catch (Win32Exception ex) when (ex.NativeErrorCode == ErrorAccessDenied)
+            {
+                throw new ElevationRequiredException(pid, ex);
+            }
             catch (ArgumentException)
             {
                 // Process doesn't exist
@@ -65,6 +123,7 @@ public class ProcessKillerService
         try
         {
             using var process = Process.GetProcessById(pid);
+            EnsureNotProtected(process);
 
             // Try graceful close first
             var closedGracefully = process.CloseMainWindow();
@@ -86,6 +145,14 @@ public class ProcessKillerService
 
             return true;
         }
+        catch (ProtectedProcessException)
+        {
+            throw;
+        }
+        catch (Win32Exception ex) when (ex.NativeErrorCode == ErrorAccessDenied)
+        {
+            throw new ElevationRequiredException(pid, ex);
+        }
         catch (ArgumentException)
         {
             // Process doesn't exist anymore
@@ -98,6 +165,168 @@ public class ProcessKillerService
         }
     }
 
+    /// <summary>
+    /// Stops a Windows service through the Service Control Manager (via WMI).
+    /// Killing a service's PID directly either fails or triggers the service's
+    /// recovery actions, which restart it immediately.
+    /// </summary>
+    public async Task<bool> StopServiceAsync(string serviceName)
+    {
+        EnsureServiceNotProtected(serviceName);
+
+        return await Task.Run(async () =>
+        {
+            try
+            {
+                var escapedName = serviceName.Replace("\\", "\\\\").Replace("'", "\\'");
+                using var service = new System.Management.ManagementObject($"Win32_Service.Name='{escapedName}'");
+
+                // 0 = success, 2 = access denied, 6 = service not active
+                var result = Convert.ToUInt32(service.InvokeMethod("StopService", null));
+                if (result == 2)
+                    throw new ElevationRequiredException(serviceName);
+                if (result != 0 && result != 6)
+                    throw new ServiceStopException(serviceName, DescribeStopServiceResult(result));
+
+                return await WaitForServiceStoppedAsync(service, serviceName);
+            }
+            catch (Exception ex) when (ex is ElevationRequiredException or ServiceStopException)
+            {
+                throw;
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"Error stopping service {serviceName}: {ex.Message}");
+                return false;
+            }
+        });
+    }
+
+    /// <summary>
+    /// Polls the SCM until the service reports Stopped.
+    /// Stop requests return as soon as the service is signalled, long before it has released its port.
+    /// </summary>
+    private static async Task<bool> WaitForServiceStoppedAsync(System.Management.ManagementObject service, string serviceName)
+    {
+        for (int i = 0; i < 20; i++)
+        {
+            service.Get();
+            if (service["State"]?.ToString() == "Stopped")
+                return true;
+            await Task.Delay(500);
+        }
+
+        throw new ServiceStopException(serviceName, "It did not stop within 10 seconds and may still be shutting down.");
+    }
+
+    /// <summary>
+    /// Explains a Win32_Service.StopService return code
+    /// </summary>
+    private static string DescribeStopServiceResult(uint result) => result switch
+    {
+        1 => "The service does not accept stop requests.",
+        3 => "Other running services depend on it. Stop the dependent services first.",
+        4 or 5 => "The service cannot accept the stop request in its current state (it may be starting or already stopping).",
+        7 => "The service did not respond to the stop request in time.",
+        _ => $"Windows returned error code {result}."
+    };
+
+    /// <summary>
+    /// Kills a process tree through an elevated taskkill (UAC prompt).
+    /// Mirrors the sudo fallback on Unix for processes owned by other users or SYSTEM.
+    /// </summary>
+    public Task<bool> KillProcessElevatedAsync(int pid)
+    {
+        return KillProcessesElevatedAsync(new[] { pid });
+    }
+
+    /// <summary>
+    /// Kills several process trees through one elevated taskkill, so a batch
+    /// costs a single UAC prompt.
+    /// Returns true once the user accepted elevation: taskkill exits non-zero when any
+    /// listed PID had already exited, even though the rest of the batch was killed.
+    /// </summary>
+    public async Task<bool> KillProcessesElevatedAsync(IEnumerable<int> pids)
+    {
+        var pidArgs = string.Join(" ", pids.Distinct().Select(pid => $"/PID {pid}"));
+        return await RunElevatedAsync("taskkill.exe", $"{pidArgs} /T /F") != null;
+    }
+
+    /// <summary>
+    /// Stops a Windows service through an elevated sc.exe (UAC prompt) and
+    /// waits for the SCM to report it as stopped.
+    /// </summary>
+    public async Task<bool> StopServiceElevatedAsync(string serviceName)
+    {
+        EnsureServiceNotProtected(serviceName);
+
+        // 0 = stop requested, 1062 = service not started
+        var exitCode = await RunElevatedAsync("sc.exe", $"stop \"{serviceName}\"");
+        if (exitCode != 0 && exitCode != ErrorServiceNotActive)
+            return false;
+
+        return await Task.Run(async () =>
+        {
+            try
+            {
+                var escapedName = serviceName.Replace("\\", "\\\\").Replace("'", "\\'");
+                using var service = new System.Management.ManagementObject($"Win32_Service.Name='{escapedName}'");
+                return await WaitForServiceStoppedAsync(service, serviceName);
+            }
+            catch (Exception ex) when (ex is not ServiceStopException)
+            {
+                Debug.WriteLine($"Error waiting for service {serviceName}: {ex.Message}");
+                return false;
+            }
+        });
+    }
+
+    /// <summary>
+    /// Deletes netsh portproxy rules through a single elevated command (one UAC prompt).
+    /// Editing portproxy rules always requires administrator privileges.
+    /// </summary>
+    public async Task<bool> RemovePortProxyRulesAsync(IReadOnlyList<PortProxyRule> rules)
+    {
+        var commands = rules.Select(rule =>
+            $"netsh interface portproxy delete {rule.Kind} listenport={rule.ListenPort} listenaddress={rule.ListenAddress}");
+        return await RunElevatedAsync("cmd.exe", $"/c {string.Join(" && ", commands)}") == 0;
+    }
+
+    /// <summary>
+    /// Runs a command with the "runas" verb so Windows shows the UAC prompt.
+    /// Returns the command's exit code, or null if the user declined elevation
+    /// or the command could not be started.
+    /// </summary>
+    private static async Task<int?> RunElevatedAsync(string fileName, string arguments)
+    {
+        try
+        {
+            var startInfo = new ProcessStartInfo(fileName, arguments)
+            {
+                UseShellExecute = true,
+                Verb = "runas",
+                WindowStyle = ProcessWindowStyle.Hidden
+            };
+
+            using var process = Process.Start(startInfo);
+            if (process == null)
+                return null;
+
+            await process.WaitForExitAsync();
+            return process.ExitCode;
+        }
+        catch (Win32Exception ex) when (ex.NativeErrorCode == ErrorCancelled)
+        {
+            // User declined the UAC prompt
+            return null;
+        }
+        catch (Exception ex)
+        {
+            Debug.WriteLine($"Error running elevated {fileName}: {ex.Message}");
+            return null;
+        }
+    }
+
     /// <summary>
     /// Kills all processes listening on a specific port
     /// </summary>
@@ -110,6 +339,9 @@ public class ProcessKillerService
         int killedCount = 0;
         foreach (var portInfo in processesOnPort)
         {
+            if (IsProtectedProcess(portInfo.Pid, portInfo.ProcessName))
+                continue;
+
             var success = await KillProcessGracefullyAsync(portInfo.Pid);
             if (success)
                 killedCount++;
@@ -118,6 +350,24 @@ public class ProcessKillerService
         return killedCount;
     }
 
+    /// <summary>
+    /// Throws if the process is on the protected system process list
+    /// </summary>
+    private static void EnsureNotProtected(Process process)
+    {
+        if (IsProtectedProcess(process.Id, process.ProcessName))
+            throw new ProtectedProcessException(process.Id, process.ProcessName);
+    }
+
+    /// <summary>
+    /// Throws if the service is on the protected system service list
+    /// </summary>
+    private static void EnsureServiceNotProtected(string serviceName)
+    {
+        if (IsProtectedService(serviceName))
+            throw new ProtectedProcessException(serviceName);
+    }
+
     /// <summary>
     /// Check if process exists and is running
     /// </summary>
@@ -134,3 +384,57 @@ public class ProcessKillerService
         }
     }
 }
+
+/// <summary>
+/// Thrown when a kill targets a critical Windows system process or service.
+/// </summary>
+public class ProtectedProcessException : Exception
+{
+    public int Pid { get; }
+    public string ProcessName { get; }
+
+    public ProtectedProcessException(int pid, string processName)
+        : base($"{processName} (PID {pid}) is a critical Windows system process. " +
+               "Terminating it could crash or destabilize Windows, so PortKiller will not kill it.")
+    {
+        Pid = pid;
+        ProcessName = processName;
+    }
+
+    public ProtectedProcessException(string serviceName)
+        : base($"\"{serviceName}\" is a core Windows service. " +
+               "Stopping it could break networking or destabilize Windows, so PortKiller will not stop it.")
+    {
+        ProcessName = serviceName;
+    }
+}
+
+/// <summary>
+/// Thrown when a kill or service stop fails because PortKiller is not elevated.
+/// </summary>
+public class ElevationRequiredException : Exception
+{
+    public ElevationRequiredException(int pid, Exception innerException)
+        : base($"Access denied while terminating PID {pid}. Administrator privileges are required.", innerException)
+    {
+    }
+
+    public ElevationRequiredException(string serviceName)
+        : base($"Access denied while stopping service {serviceName}. Administrator privileges are required.")
+    {
+    }
+}
+
+/// <summary>
+/// Thrown when the Service Control Manager refuses or fails to stop a service.
+/// </summary>
+public class ServiceStopException : Exception
+{
+    public string ServiceName { get; }
+
+    public ServiceStopException(string serviceName, string reason)
+        : base($"Could not stop service \"{serviceName}\". {reason}")
+    {
+        ServiceName = serviceName;
+    }
+}
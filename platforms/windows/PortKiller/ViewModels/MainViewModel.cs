@@ -27,6 +27,7 @@ public partial class MainViewModel : ObservableObject
     
     private CancellationTokenSource? _refreshCancellation;
     private Dictionary<int, bool> _previousPortStates = new();
+    private Dictionary<int, (HashSet<int> Pids, string ProcessName)> _previousPortOwners = new();
 
     // Observable Properties
     [ObservableProperty]
@@ -59,6 +60,26 @@ public partial class MainViewModel : ObservableObject
     [ObservableProperty]
     private bool _showNotifications = true;
 
+    /// <summary>
+    /// Raised with a user-facing message when a kill is refused
+    /// </summary>
+    public event EventHandler<string>? KillRefused;
+
+    /// <summary>
+    /// Raised with a user-facing message when a service could not be stopped
+    /// </summary>
+    public event EventHandler<string>? KillFailed;
+
+    /// <summary>
+    /// Raised when a kill failed for lack of privileges and can be retried elevated
+    /// </summary>
+    public event EventHandler<PortInfo>? ElevationRequired;
+
+    /// <summary>
+    /// Raised once after Kill All with every port that was denied for lack of privileges
+    /// </summary>
+    public event EventHandler<IReadOnlyList<PortInfo>>? BatchElevationRequired;
+
     public MainViewModel(
         PortScannerService scanner,
         ProcessKillerService killer,
@@ -190,7 +211,13 @@ public partial class MainViewModel : ObservableObject
             // Set UI state for spinner
             port.IsKilling = true;
             
-            var success = await _killer.KillProcessGracefullyAsync(port.Pid);
+            // Portproxy listeners are freed by deleting the rule, not by killing svchost.
+            // Services must be stopped through the SCM, otherwise recovery restarts them.
+            var success = port.IsPortProxy
+                ? await _killer.RemovePortProxyRulesAsync(port.ProxyRules)
+                : port.IsService
+                    ? await _killer.StopServiceAsync(port.ServiceName!)
+                    : await _killer.KillProcessGracefullyAsync(port.Pid);
             if (success)
             {
                 // Refresh immediately to show change
@@ -203,6 +230,21 @@ public partial class MainViewModel : ObservableObject
                 port.IsKilling = false;
             }
         }
+        catch (ProtectedProcessException ex)
+        {
+            port.IsKilling = false;
+            KillRefused?.Invoke(this, ex.Message);
+        }
+        catch (ElevationRequiredException)
+        {
+            port.IsKilling = false;
+            ElevationRequired?.Invoke(this, port);
+        }
+        catch (ServiceStopException ex)
+        {
+            port.IsKilling = false;
+            KillFailed?.Invoke(this, ex.Message);
+        }
         catch (Exception ex)
         {
             Debug.WriteLine($"Error killing process: {ex.Message}");
@@ -210,6 +252,128 @@ public partial class MainViewModel : ObservableObject
         }
     }
 
+    /// <summary>
+    /// Retries a kill (or service stop) through an elevated helper process.
+    /// Windows shows a UAC prompt; declining it leaves the process running.
+    /// </summary>
+    [RelayCommand]
+    public async Task KillElevatedAsync(PortInfo? port)
+    {
+        if (port == null || !port.IsActive)
+            return;
+
+        port.IsKilling = true;
+
+        try
+        {
+            var success = port.IsService
+                ? await _killer.StopServiceElevatedAsync(port.ServiceName!)
+                : await _killer.KillProcessElevatedAsync(port.Pid);
+
+            if (success)
+            {
+                await Task.Delay(500);
+                await RefreshPortsAsync();
+            }
+            else
+            {
+                port.IsKilling = false;
+            }
+        }
+        catch (ProtectedProcessException ex)
+        {
+            port.IsKilling = false;
+            KillRefused?.Invoke(this, ex.Message);
+        }
+        catch (ServiceStopException ex)
+        {
+            port.IsKilling = false;
+            KillFailed?.Invoke(this, ex.Message);
+        }
+    }
+
+    /// <summary>
+    /// Kills every Kill All candidate. Ports denied for lack of privileges are
+    /// collected and reported once through BatchElevationRequired instead of
+    /// raising one ElevationRequired dialog per port.
+    /// </summary>
+    [RelayCommand]
+    public async Task KillAllAsync()
+    {
+        var denied = new List<PortInfo>();
+
+        foreach (var port in Ports.Where(IsKillAllCandidate).ToList())
+        {
+            port.IsKilling = true;
+            try
+            {
+                if (!await _killer.KillProcessGracefullyAsync(port.Pid))
+                    port.IsKilling = false;
+            }
+            catch (ElevationRequiredException)
+            {
+                port.IsKilling = false;
+                denied.Add(port);
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"Failed to kill process on port {port.Port}: {ex.Message}");
+                port.IsKilling = false;
+            }
+        }
+
+        await Task.Delay(500);
+        await RefreshPortsAsync();
+
+        if (denied.Count > 0)
+            BatchElevationRequired?.Invoke(this, denied);
+    }
+
+    /// <summary>
+    /// Retries a batch of denied kills through a single elevated taskkill (one UAC prompt)
+    /// </summary>
+    [RelayCommand]
+    public async Task KillAllElevatedAsync(IReadOnlyList<PortInfo>? ports)
+    {
+        if (ports == null || ports.Count == 0)
+            return;
+
+        foreach (var port in ports)
+            port.IsKilling = true;
+
+        if (await _killer.KillProcessesElevatedAsync(ports.Select(p => p.Pid)))
+        {
+            await Task.Delay(500);
+            await RefreshPortsAsync();
+        }
+        else
+        {
+            foreach (var port in ports)
+                port.IsKilling = false;
+        }
+    }
+
+    /// <summary>
+    /// Whether the port's process (or service) is a critical system component that cannot be stopped
+    /// </summary>
+    public bool IsProtected(PortInfo port)
+    {
+        if (port.IsPortProxy)
+            return false;
+        if (port.IsService)
+            return ProcessKillerService.IsProtectedService(port.ServiceName!);
+        return ProcessKillerService.IsProtectedProcess(port.Pid, port.ProcessName);
+    }
+
+    /// <summary>
+    /// Whether Kill All should target this port.
+    /// Services, portproxy rules and WSL2 forwards are left alone: stopping a service,
+    /// deleting a user's forwarding configuration or killing the relay behind every
+    /// forwarded WSL port is a deliberate, per-port action.
+    /// </summary>
+    private bool IsKillAllCandidate(PortInfo port) =>
+        port.IsActive && !port.IsService && !port.IsPortProxy && !port.IsWslForward && !IsProtected(port);
+
     // Favorites Management
     [RelayCommand]
     public void ToggleFavorite(int port)
@@ -271,6 +435,8 @@ public partial class MainViewModel : ObservableObject
         {
             var isActive = activePorts.Contains(watched.Port);
             var wasActive = _previousPortStates.GetValueOrDefault(watched.Port, false);
+            var owners = Ports.Where(p => p.IsActive && p.Port == watched.Port).ToList();
+            var ownerPids = owners.Select(p => p.Pid).ToHashSet();
 
             // Port just started
             if (isActive && !wasActive && watched.NotifyOnStart)
@@ -285,7 +451,22 @@ public partial class MainViewModel : ObservableObject
                 _notifications.NotifyPortStopped(watched.Port);
             }
 
+            // Same port, entirely new PIDs (e.g. a crash-looping server restarted).
+            // Owners are compared as sets: a port held by several PIDs comes back
+            // from the scan in no stable order.
+            if (isActive && wasActive && watched.NotifyOnStart && owners.Count > 0
+                && _previousPortOwners.TryGetValue(watched.Port, out var previous)
+                && !previous.Pids.Overlaps(ownerPids))
+            {
+                _notifications.NotifyPortOwnerChanged(
+                    watched.Port, previous.ProcessName, previous.Pids, owners[0].ProcessName, ownerPids);
+            }
+
             _previousPortStates[watched.Port] = isActive;
+            if (owners.Count > 0)
+                _previousPortOwners[watched.Port] = (ownerPids, owners[0].ProcessName);
+            else
+                _previousPortOwners.Remove(watched.Port);
         }
     }
 